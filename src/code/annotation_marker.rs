@@ -0,0 +1,63 @@
+use std::sync::RwLock;
+
+pub const DEFAULT_ANNOTATION_MARKERS: [&str; 8] = [
+    "TODO", "FIXME", "FIX", "INFO", "NOTE", "HACK", "WARN", "BUG",
+];
+
+// INFO: Markers set by the user, the default markers are used when it is None.
+static ANNOTATION_MARKERS: RwLock<Option<Vec<String>>> = RwLock::new(None);
+
+pub fn set_annotation_markers(markers: &[String]) {
+    let mut annotation_markers = match ANNOTATION_MARKERS.write() {
+        Ok(annotation_markers) => annotation_markers,
+        Err(e) => e.into_inner(),
+    };
+
+    *annotation_markers = Some(markers.to_vec());
+}
+
+// INFO: Index after a marker like `TODO:` or `TODO(name):` and the whitespace after it, at the
+// start of the comment. Returns 0 when the comment does not start with a marker.
+pub fn get_marker_end(comment: &str) -> usize {
+    let annotation_markers = match ANNOTATION_MARKERS.read() {
+        Ok(annotation_markers) => annotation_markers,
+        Err(e) => e.into_inner(),
+    };
+
+    return match annotation_markers.as_deref() {
+        Some(markers) => find_marker_end(comment, markers.iter().map(String::as_str)),
+        None => find_marker_end(comment, DEFAULT_ANNOTATION_MARKERS.into_iter()),
+    };
+}
+
+fn find_marker_end<'m>(comment: &str, markers: impl Iterator<Item = &'m str>) -> usize {
+    let content = comment.trim_start();
+    let marker_start = comment.len() - content.len();
+
+    for marker in markers {
+        let rest = match content.strip_prefix(marker) {
+            Some(rest) => rest,
+            None => continue,
+        };
+
+        // INFO: The owner of the marker, like `TODO(name):`.
+        let rest = match rest.strip_prefix('(') {
+            Some(owner) => match owner.find(')') {
+                Some(owner_end) if !owner[..owner_end].contains(char::is_whitespace) => {
+                    &owner[owner_end + 1..]
+                }
+                _ => continue,
+            },
+            None => rest,
+        };
+
+        let rest = match rest.strip_prefix(':') {
+            Some(rest) => rest,
+            None => continue,
+        };
+
+        return marker_start + content.len() - rest.trim_start().len();
+    }
+
+    return 0;
+}
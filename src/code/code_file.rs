@@ -9,18 +9,30 @@ use log::{error, warn};
 use tokio::task::JoinHandle;
 
 use crate::{
-    code::programming::{ProgrammingStringSyntax, LUA, RUST},
-    common::string::{DelimiterType, StringDelimiter, StringDelimiterSlice, StringSlice},
+    code::programming::{ProgrammingStringSyntax, StringInterpolationSyntax},
+    common::string::{DelimiterType, StringDelimiterSlice, StringSlice},
     nvim_lang_dictionary::NvimLanguageReadonlyDictionary,
 };
 
-use super::programming::{CodeBlockLineSyntax, ProgrammingLanguage};
+use super::{
+    annotation_marker,
+    doc_comment::{self, DocCommentLine},
+    filetype::{self, FileLanguage, ProseGenerator},
+    line_comment::{self, LineComment},
+    programming::{
+        BlockDelimiter, CodeBlockLineSyntax, ProgrammingLanguage, ProgrammingLanguageType,
+    },
+    prose::Prose,
+    rust_literal::OpenAttribute,
+    string_context::{self, StringContext},
+};
 
 #[derive(Debug)]
 pub struct CodeFile {
     pub file_path: String,
     pub blocks: Vec<CodeBlock>,
     pub lines: Vec<Code>,
+    pub proses: Vec<Prose>,
     nvim_language_readonly_dictionary: Arc<NvimLanguageReadonlyDictionary>,
 }
 
@@ -29,39 +41,72 @@ impl CodeFile {
         file_path: String,
         nvim_language_readonly_dictionary: Arc<NvimLanguageReadonlyDictionary>,
     ) -> Option<Self> {
-        if file_path.ends_with(&RUST.extension) {
-            return Some(
-                CodeFile {
-                    file_path,
-                    blocks: Vec::new(),
-                    lines: Vec::new(),
-                    nvim_language_readonly_dictionary: nvim_language_readonly_dictionary,
-                }
-                .generate(&RUST)
-                .await,
-            );
-        }
+        return CodeFile::new_with_filetype(
+            file_path,
+            None,
+            None,
+            nvim_language_readonly_dictionary,
+        )
+        .await;
+    }
+
+    // INFO: The Neovim `filetype` decides the language when it is given, otherwise it is detected
+    // from a modeline, the file path or the shebang. The Neovim `commentstring` is the fallback
+    // for unknown languages.
+    pub async fn new_with_filetype(
+        file_path: String,
+        filetype: Option<String>,
+        commentstring: Option<String>,
+        nvim_language_readonly_dictionary: Arc<NvimLanguageReadonlyDictionary>,
+    ) -> Option<Self> {
+        let file_language =
+            filetype::detect(&file_path, filetype.as_deref(), commentstring.as_deref())?;
+
+        let code_file = CodeFile {
+            file_path,
+            blocks: Vec::new(),
+            lines: Vec::new(),
+            proses: Vec::new(),
+            nvim_language_readonly_dictionary: nvim_language_readonly_dictionary,
+        };
+
+        return match file_language {
+            FileLanguage::Programming(lang) => Some(code_file.generate(lang).await),
+            FileLanguage::Prose(generate) => Some(code_file.generate_prose(generate)),
+        };
+    }
+
+    // INFO: For files where the whole document is prose, like Markdown, a commit message or a
+    // config file.
+    fn generate_prose(mut self, generate: ProseGenerator) -> Self {
+        let file_result = File::open(&self.file_path);
+
+        let file = match file_result {
+            Ok(file) => file,
+            Err(e) => {
+                error!("Unable to open file: {}, error: {}", self.file_path, e);
+                return self;
+            }
+        };
 
-        if file_path.ends_with(&LUA.extension) {
-            return Some(
-                CodeFile {
-                    file_path,
-                    blocks: Vec::new(),
-                    lines: Vec::new(),
-                    nvim_language_readonly_dictionary: nvim_language_readonly_dictionary,
+        let mut lines = Vec::new();
+
+        for line_res in BufReader::new(file).lines() {
+            match line_res {
+                Ok(line) => lines.push(line),
+                Err(e) => {
+                    warn!("Unable to read file line. {}", e);
+                    lines.push(String::new());
                 }
-                .generate(&LUA)
-                .await,
-            );
+            };
         }
 
-        return None;
+        self.proses = generate(&lines);
+
+        return self;
     }
 
-    async fn generate<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize>(
-        mut self,
-        lang: &'static ProgrammingLanguage<OPERATOR_COUNT, RESERVED_KEYWORD_COUNT>,
-    ) -> Self {
+    async fn generate(mut self, lang: &'static ProgrammingLanguage) -> Self {
         let file_result = File::open(&self.file_path);
 
         let file = match file_result {
@@ -77,6 +122,10 @@ impl CodeFile {
         let mut hasher = DefaultHasher::new();
         let mut line_handles: Vec<JoinHandle<Vec<Code>>> = Vec::new();
         let mut code_block: Option<CodeBlock> = None;
+        let mut is_directive_continuation = false;
+        let mut open_attribute: Option<OpenAttribute> = None;
+        let mut doc_comment_lines: Vec<DocCommentLine> = Vec::new();
+        let mut line_comments: Vec<LineComment> = Vec::new();
 
         for (index, line_res) in file_buf_reader.lines().enumerate() {
             let line = match line_res {
@@ -90,58 +139,165 @@ impl CodeFile {
             let line_number = index + 1;
             line.hash(&mut hasher);
 
+            // INFO: Doc comments are Markdown, consecutive doc comment lines are one prose.
             if matches!(code_block, None) {
-                // INFO: Ignore new line in code file
-                if line.is_empty() {
+                if let Some(doc_comment_line) = lang.get_doc_comment_line(line_number, &line) {
+                    self.proses.extend(line_comment::generate(&line_comments));
+                    line_comments.clear();
+                    doc_comment_lines.push(doc_comment_line);
                     continue;
                 }
+            }
+
+            if !doc_comment_lines.is_empty() {
+                self.proses
+                    .extend(doc_comment::generate(&doc_comment_lines));
+                doc_comment_lines.clear();
+            }
+
+            // INFO: Consecutive line comments are one prose, like a sentence wrapped over lines.
+            if matches!(code_block, None) && !(line_number == 1 && line.starts_with("#!")) {
+                if let Some(line_comment) = lang.get_line_comment(line_number, &line) {
+                    // INFO: Disabled code ends the paragraph and is not checked.
+                    if lang.is_commented_out_code(&line_comment.content) {
+                        self.proses.extend(line_comment::generate(&line_comments));
+                        line_comments.clear();
+                        continue;
+                    }
+
+                    line_comments.push(line_comment);
+                    continue;
+                }
+            }
+
+            if !line_comments.is_empty() {
+                self.proses.extend(line_comment::generate(&line_comments));
+                line_comments.clear();
+            }
+
+            // INFO: The rest of a line after a block ends on it is handled like a new line, like
+            // `*/ let value = "...";`.
+            let mut line = line;
+
+            loop {
+                if matches!(code_block, None) {
+                    // INFO: Ignore new line in code file
+                    if line.is_empty() {
+                        break;
+                    }
 
-                code_block = match lang.is_start_of_code_block(&line) {
-                    super::programming::CodeBlockType::String(code_block_current_line_syntax) => {
-                        Some(CodeBlock::new(
+                    // INFO: Ignore the shebang, like `#!/bin/bash`, but not Rust inner attributes like
+                    // `#![doc = "..."]`.
+                    if line_number == 1 && filetype::find_shebang_filetype(&line).is_some() {
+                        break;
+                    }
+
+                    let (code_proses, line) =
+                        lang.generate_code_proses(line_number, line, &mut open_attribute);
+                    self.proses.extend(code_proses);
+
+                    let code_block_type = lang.is_start_of_code_block(&line);
+
+                    // INFO: Code, comments and strings before the block on the same line.
+                    if let super::programming::CodeBlockType::String(code_block_line_syntax)
+                    | super::programming::CodeBlockType::Comment(code_block_line_syntax) =
+                        &code_block_type
+                    {
+                        let code = line[..code_block_line_syntax.start_indexof].to_owned();
+
+                        if !code.trim().is_empty() {
+                            line_handles.push(tokio::task::spawn(Code::generate_code_line(
+                                CodeLine::new(hasher.finish(), line_number, line.clone()),
+                                code,
+                                false,
+                                lang,
+                                nvim_language_readonly_dictionary.clone(),
+                            )));
+                        }
+                    }
+
+                    code_block = match code_block_type {
+                        super::programming::CodeBlockType::String(
+                            code_block_current_line_syntax,
+                        ) => Some(CodeBlock::new(
                             line_number,
                             hasher.finish(),
                             line,
                             BlockType::String,
                             code_block_current_line_syntax,
-                        ))
-                    }
-                    super::programming::CodeBlockType::Comment(code_block_syntax) => {
-                        Some(CodeBlock::new(
-                            line_number,
-                            hasher.finish(),
-                            line,
-                            BlockType::Comment,
-                            code_block_syntax,
-                        ))
-                    }
-                    super::programming::CodeBlockType::None => {
-                        let hash = hasher.finish();
-                        line_handles.push(tokio::task::spawn(Code::generate(
-                            hash,
-                            line_number,
-                            line,
                             lang,
-                            nvim_language_readonly_dictionary.clone(),
-                        )));
-                        code_block
-                    }
-                };
+                        )),
+                        super::programming::CodeBlockType::Comment(code_block_syntax) => {
+                            Some(CodeBlock::new(
+                                line_number,
+                                hasher.finish(),
+                                line,
+                                BlockType::Comment,
+                                code_block_syntax,
+                                lang,
+                            ))
+                        }
+                        super::programming::CodeBlockType::None => {
+                            // INFO: Preprocessor directives can continue on the next line with `\`.
+                            let is_directive =
+                                is_directive_continuation || lang.is_preprocessor_directive(&line);
+                            is_directive_continuation =
+                                is_directive && line.trim_end().ends_with('\\');
+
+                            let hash = hasher.finish();
+                            line_handles.push(tokio::task::spawn(Code::generate(
+                                hash,
+                                line_number,
+                                line,
+                                is_directive,
+                                lang,
+                                nvim_language_readonly_dictionary.clone(),
+                            )));
+                            code_block
+                        }
+                    };
 
-                continue;
-            }
+                    break;
+                }
 
-            if let Some(cb) = code_block {
-                let (current_code_block, push_code_block) =
-                    cb.push(line_number, line, &mut hasher, lang);
-                code_block = current_code_block;
+                if let Some(cb) = code_block {
+                    let (current_code_block, push_code_block, rest_line) =
+                        cb.push(line_number, line, &mut hasher, lang);
+                    code_block = current_code_block;
+
+                    if let Some(mut push_code_block) = push_code_block {
+                        for (code_line, interpolation) in push_code_block.interpolations.drain(..) {
+                            line_handles.push(tokio::task::spawn(Code::generate_code_line(
+                                code_line,
+                                interpolation,
+                                false,
+                                lang,
+                                nvim_language_readonly_dictionary.clone(),
+                            )));
+                        }
+
+                        self.blocks.push(push_code_block);
+                    }
 
-                if let Some(push_code_block) = push_code_block {
-                    self.blocks.push(push_code_block);
+                    if let Some(rest_line) = rest_line {
+                        line = rest_line;
+                        continue;
+                    }
                 }
+
+                break;
             }
         }
 
+        if !doc_comment_lines.is_empty() {
+            self.proses
+                .extend(doc_comment::generate(&doc_comment_lines));
+        }
+
+        if !line_comments.is_empty() {
+            self.proses.extend(line_comment::generate(&line_comments));
+        }
+
         for line_handle in line_handles {
             match line_handle.await {
                 Ok(codes) => self.lines.extend(codes),
@@ -162,6 +318,18 @@ pub struct CodeBlock {
     pub lines: Vec<CodeLine>,
     pub block_type: BlockType,
     pub code_block_current_line_syntax: CodeBlockLineSyntax,
+    // INFO: Code inside of string interpolations, like JavaScript template literal `${value}`.
+    pub interpolations: Vec<(CodeLine, String)>,
+    // INFO: Byte ranges of format placeholders in string blocks, like `{}` or `%s`.
+    pub placeholders: Vec<(usize, usize)>,
+    // INFO: Call the string block is in, found from the code before the start delimiter.
+    pub string_context: StringContext,
+    // INFO: Count of open blocks, more than one in nested blocks.
+    depth: usize,
+    // INFO: Offset in the untrimmed block and column in the original line where every line starts.
+    line_starts: Vec<(usize, usize)>,
+    // INFO: Length of the whitespace trimmed from the start of the block.
+    trim_len: usize,
 }
 
 impl CodeBlock {
@@ -171,6 +339,7 @@ impl CodeBlock {
         line: String,
         block_type: BlockType,
         code_block_current_line_syntax: CodeBlockLineSyntax,
+        lang: &'static ProgrammingLanguage,
     ) -> Self {
         let code_line = CodeLine {
             hash,
@@ -178,74 +347,240 @@ impl CodeBlock {
             original_line: line,
         };
 
-        let line_split = match code_block_current_line_syntax.start_delimiter {
-            DelimiterType::DelimiterStr(s) => code_line.original_line.split_once(s),
-            DelimiterType::DelimiterChar(c) => code_line.original_line.split_once(c),
-            DelimiterType::None => None,
-        };
+        // INFO: The block starts after the start delimiter, the line can have strings before it.
+        let block_start = code_block_current_line_syntax
+            .start_indexof
+            .saturating_add(code_block_current_line_syntax.start_delimiter.len());
 
-        let block = match line_split {
-            Some((_, right)) => right,
+        let block_start = match code_line.original_line.get(block_start..) {
+            // INFO: Heredoc bodies start on the next line.
+            Some(_) if code_block_current_line_syntax.is_end_line => code_line.original_line.len(),
+            Some(_) => block_start,
             None => {
                 error!(
                     "Error in CodeBlock::New, unable to split line {}",
                     code_line.original_line
                 );
-                "".into()
+                code_line.original_line.len()
             }
         };
+        let block = &code_line.original_line[block_start..];
+
+        let mut depth = 1;
+        code_block_current_line_syntax.find_end(block, &mut depth);
+        let block = code_block_current_line_syntax.mask_nested_delimiters(block, false);
 
-        let mut block = block.to_string();
+        let mut block = match block_type {
+            BlockType::Comment => {
+                CodeBlock::mask_comment_line(&code_block_current_line_syntax, &block, lang)
+            }
+            BlockType::String => code_block_current_line_syntax.mask_line_continuation(&block),
+        };
         block.push('\n');
 
+        // INFO: The code after a string block is on its last line, only the call before it is
+        // known.
+        let string_context = match block_type {
+            BlockType::String => {
+                let start_indexof = code_block_current_line_syntax
+                    .start_indexof
+                    .min(code_line.original_line.len());
+                let prefix = lang.get_string_prefix(&code_line.original_line, start_indexof);
+
+                string_context::classify(
+                    &code_line.original_line[..start_indexof - prefix.len()],
+                    "",
+                )
+            }
+            BlockType::Comment => StringContext::Unknown,
+        };
+
         return Self {
             hash: 0,
             block,
             lines: vec![code_line],
             block_type,
             code_block_current_line_syntax,
+            interpolations: Vec::new(),
+            placeholders: Vec::new(),
+            string_context,
+            depth,
+            line_starts: vec![(0, block_start)],
+            trim_len: 0,
         };
     }
 
-    pub fn push<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize>(
+    pub fn push(
         mut self,
         line_number: usize,
         line: String,
         hasher: &mut DefaultHasher,
-        lang: &'static ProgrammingLanguage<OPERATOR_COUNT, RESERVED_KEYWORD_COUNT>,
-    ) -> (Option<CodeBlock>, Option<CodeBlock>) {
-        self.block.push_str(&line);
+        lang: &'static ProgrammingLanguage,
+    ) -> (Option<CodeBlock>, Option<CodeBlock>, Option<String>) {
+        let end_indexof = self.end_indexof(&line);
+
+        // INFO: The rest of the line after the end delimiter, the block is masked with whitespace
+        // so the columns are kept.
+        let rest_line = end_indexof
+            .filter(|end_indexof| !line[*end_indexof..].trim().is_empty())
+            .map(|end_indexof| format!("{}{}", " ".repeat(end_indexof), &line[end_indexof..]));
+
+        // INFO: Code after the end delimiter is not part of the block.
+        let block_line = match end_indexof {
+            Some(end_indexof) => &line[..end_indexof],
+            None => &line,
+        };
+        let block_line = self
+            .code_block_current_line_syntax
+            .mask_nested_delimiters(block_line, end_indexof.is_some());
+
+        self.line_starts.push((self.block.len(), 0));
+
+        match self.block_type {
+            BlockType::Comment => self.block.push_str(&CodeBlock::mask_comment_line(
+                &self.code_block_current_line_syntax,
+                &block_line,
+                lang,
+            )),
+            BlockType::String => self.block.push_str(
+                &self
+                    .code_block_current_line_syntax
+                    .mask_line_continuation(&block_line),
+            ),
+        }
         self.block.push('\n');
 
-        let is_end = self.is_end(&line);
-
         self.push_line(hasher.finish(), line_number, line);
 
-        if is_end {
+        if let Some(end_indexof) = end_indexof {
             self.block.hash(hasher);
             self.hash = hasher.finish();
+            self.trim_len = self.block.len() - self.block.trim_start().len();
             self.block = self
-                .block
-                .trim()
-                .trim_end_by_delimiter(&self.code_block_current_line_syntax.end_delimiter)
+                .code_block_current_line_syntax
+                .end_delimiter
+                .trim_end(self.block.trim())
                 .trim()
                 .trim_end_matches(&lang.comment_delimiter)
                 .trim()
                 .to_owned();
-            return (None, Some(self));
+            self.split_interpolations(lang, end_indexof);
+
+            if matches!(self.block_type, BlockType::String) {
+                self.placeholders = lang.find_format_placeholders(&self.block);
+            }
+
+            return (None, Some(self), rest_line);
+        }
+
+        return (Some(self), None, None);
+    }
+
+    // INFO: Replace the decoration, markers like `TODO:` and disabled code, like `foo(); */`,
+    // with whitespace.
+    fn mask_comment_line(
+        code_block_line_syntax: &CodeBlockLineSyntax,
+        line: &str,
+        lang: &'static ProgrammingLanguage,
+    ) -> String {
+        let line = lang.mask_block_comment_line(line);
+        let content = line.trim_end();
+        let content = code_block_line_syntax.end_delimiter.trim_end(content);
+
+        if lang.is_commented_out_code(content) {
+            return format!("{}{}", " ".repeat(content.len()), &line[content.len()..]);
+        }
+
+        let marker_end = annotation_marker::get_marker_end(&line);
+        return format!("{}{}", " ".repeat(marker_end), &line[marker_end..]);
+    }
+
+    // INFO: `end_indexof` is the index right after the end delimiter on the last line.
+    fn split_interpolations(&mut self, lang: &'static ProgrammingLanguage, end_indexof: usize) {
+        if !matches!(self.block_type, BlockType::String) {
+            return;
         }
 
-        return (Some(self), None);
+        let first_line = match self.lines.first() {
+            Some(first_line) => &first_line.original_line,
+            None => return,
+        };
+
+        let prefix = lang.get_string_prefix(
+            first_line,
+            self.code_block_current_line_syntax.start_indexof,
+        );
+        let interpolation = match lang
+            .get_string_interpolation(prefix, &self.code_block_current_line_syntax.start_delimiter)
+        {
+            Some(interpolation) => interpolation,
+            None => return,
+        };
+
+        self.block = interpolation.split_code(&self.block).0;
+
+        let code_block_line_syntax = &self.code_block_current_line_syntax;
+        let last_index = self.lines.len().saturating_sub(1);
+
+        // INFO: Only interpolations that start and end on the same line are handled as code. Only
+        // the string body is split, the code around the string is not an interpolation.
+        for (index, (code_line, (_, column))) in
+            self.lines.iter().zip(&self.line_starts).enumerate()
+        {
+            let body_end = match index == last_index {
+                // INFO: The heredoc end line is only the end delimiter.
+                true if code_block_line_syntax.is_end_line => *column,
+                true => end_indexof.saturating_sub(code_block_line_syntax.end_delimiter.len()),
+                false => code_line.original_line.len(),
+            };
+            let body = match code_line.original_line.get(*column..body_end.max(*column)) {
+                Some(body) => body,
+                None => continue,
+            };
+
+            let (_, code) = interpolation.split_code(body);
+
+            if code.is_empty() {
+                continue;
+            }
+
+            self.interpolations.push((code_line.clone(), code));
+        }
     }
 
-    fn is_end(&self, line: &str) -> bool {
-        return match self.code_block_current_line_syntax.end_delimiter {
-            DelimiterType::DelimiterStr(s) => !matches!(line.find(s), None),
-            DelimiterType::DelimiterChar(c) => !matches!(line.find(c), None),
-            DelimiterType::None => true,
+    // INFO: Index right after the end delimiter of the block.
+    fn end_indexof(&mut self, line: &str) -> Option<usize> {
+        let code_block_current_line_syntax = &self.code_block_current_line_syntax;
+        let end_delimiter = &code_block_current_line_syntax.end_delimiter;
+
+        if self.code_block_current_line_syntax.is_end_line {
+            return match end_delimiter.as_str() {
+                Some(s) if line.trim() == s => Some(line.len()),
+                _ => None,
+            };
+        }
+
+        return match code_block_current_line_syntax.find_end(line, &mut self.depth) {
+            Some(end_indexof) => Some(end_indexof),
+            None if end_delimiter.is_empty() => Some(line.len()),
+            // INFO: A normal string without a line continuation is not valid, it ends here.
+            None if !code_block_current_line_syntax.is_continued(line) => Some(line.len()),
+            None => None,
         };
     }
 
+    // INFO: Line and column in the original line of an offset in the block.
+    pub fn position(&self, offset: usize) -> Option<(&CodeLine, usize)> {
+        let offset = offset + self.trim_len;
+        let line_index = self
+            .line_starts
+            .iter()
+            .rposition(|(line_offset, _)| *line_offset <= offset)?;
+        let (line_offset, column) = self.line_starts[line_index];
+
+        return Some((&self.lines[line_index], column + offset - line_offset));
+    }
+
     fn push_line(&mut self, hash: u64, line_number: usize, line: String) {
         let code_line = CodeLine {
             hash,
@@ -263,20 +598,52 @@ pub struct Code {
     pub value: String,
     pub line: CodeLine,
     pub tp: CodeType,
+    // INFO: Column in the original line where the value starts. Code values are the words of the
+    // whole line, so they start at zero.
+    pub column: usize,
+    // INFO: Byte ranges of format placeholders in string values, like `{}` or `%s`.
+    pub placeholders: Vec<(usize, usize)>,
+    // INFO: Call or position the string is in, like `println!` or a table key.
+    pub string_context: StringContext,
 }
 
 impl Code {
     // TODO: Should not use Vec<Code>, for know it is simple
-    async fn generate<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize>(
+    async fn generate(
         hash: u64,
         line_number: usize,
-        mut line: String,
-        lang: &'static ProgrammingLanguage<OPERATOR_COUNT, RESERVED_KEYWORD_COUNT>,
+        line: String,
+        is_directive: bool,
+        lang: &'static ProgrammingLanguage,
         nvim_language_readonly_dictionary: Arc<NvimLanguageReadonlyDictionary>,
     ) -> Vec<Code> {
         let code_line = CodeLine::new(hash, line_number, line.clone());
 
-        line = lang.post_replace_with_empty_space(line);
+        return Code::generate_code_line(
+            code_line,
+            line,
+            is_directive,
+            lang,
+            nvim_language_readonly_dictionary,
+        )
+        .await;
+    }
+
+    // INFO: Generate codes from `line`, where `line` is part of the `code_line` original line.
+    async fn generate_code_line(
+        code_line: CodeLine,
+        mut line: String,
+        is_directive: bool,
+        lang: &'static ProgrammingLanguage,
+        nvim_language_readonly_dictionary: Arc<NvimLanguageReadonlyDictionary>,
+    ) -> Vec<Code> {
+        let hash = code_line.hash;
+
+        if is_directive {
+            line = lang.strip_preprocessor_include(line);
+        }
+
+        line = lang.mask_code_literals(line);
 
         let mut line_chunk_limit_count = 0;
         let mut codes = Vec::<Code>::new();
@@ -299,7 +666,8 @@ impl Code {
                 break;
             }
 
-            let code_line_state = Code::new_in_line(hash, code_line.clone(), line, lang);
+            let code_line_state =
+                Code::new_in_line(hash, code_line.clone(), line, is_directive, lang);
 
             match code_line_state {
                 CodeLineState::ContinueWithResult(new_line, code) => {
@@ -316,6 +684,17 @@ impl Code {
             line_chunk_limit_count += 1;
         }
 
+        for code in &mut codes {
+            if matches!(code.tp, CodeType::String) {
+                code.placeholders = lang.find_format_placeholders(&code.value);
+            }
+        }
+
+        // INFO: Nothing is known about the code of a language built from the `commentstring`.
+        if matches!(lang.lang_type, ProgrammingLanguageType::CommentString) {
+            return codes;
+        }
+
         line = lang.replase_all_operators_and_syntax_with_whitespace(line);
         line = lang.replase_all_reserved_keywords_with_whitespace(
             line,
@@ -332,39 +711,49 @@ impl Code {
             value: line,
             line: code_line,
             tp: CodeType::Code,
+            column: 0,
+            placeholders: Vec::new(),
+            string_context: StringContext::Unknown,
         };
         codes.push(code);
         return codes;
     }
 
-    fn new_in_line<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize>(
+    fn new_in_line(
         hash: u64,
         code_line: CodeLine,
         mut line: String,
-        lang: &'static ProgrammingLanguage<OPERATOR_COUNT, RESERVED_KEYWORD_COUNT>,
+        is_directive: bool,
+        lang: &'static ProgrammingLanguage,
     ) -> CodeLineState {
-        let comment_delimiter = lang.comment_delimiter;
         let string_syntax_1 = &lang.string_syntax[0];
         let string_syntax_2 = &lang.string_syntax[1];
 
         let comment_indexof = line.find(lang.comment_delimiter).unwrap_or(usize::MAX);
-        let string_indexof_1 = string_syntax_1
+        let mut string_indexof_1 = string_syntax_1
             .string_delimiter
             .indexof(&line)
             .unwrap_or(usize::MAX);
-        let string_indexof_2 = string_syntax_2
+        let mut string_indexof_2 = string_syntax_2
             .string_delimiter
             .indexof(&line)
             .unwrap_or(usize::MAX);
         let comment_block_line_syntax = lang.block_comment.get_code_block_line_syntax(&line);
-        let string_block_line_syntax = lang.block_string.get_code_block_line_syntax(&line);
+        let mut string_block_line_syntax = lang.block_string.get_code_block_line_syntax(&line);
+
+        // INFO: Strings in preprocessor directives are handled as code.
+        if is_directive {
+            string_indexof_1 = usize::MAX;
+            string_indexof_2 = usize::MAX;
+            string_block_line_syntax.start_indexof = usize::MAX;
+        }
 
         if comment_indexof < string_indexof_1
             && comment_indexof < string_indexof_2
             && comment_indexof < comment_block_line_syntax.start_indexof
             && comment_indexof < string_block_line_syntax.start_indexof
         {
-            return Code::new_comment(hash, line, code_line, comment_delimiter);
+            return Code::new_comment(hash, line, code_line, lang);
         }
 
         if comment_block_line_syntax.start_indexof < string_indexof_1
@@ -380,24 +769,52 @@ impl Code {
             );
         }
 
-        if string_block_line_syntax.start_indexof < string_indexof_1
-            && string_block_line_syntax.start_indexof < string_indexof_2
+        // INFO: Block strings like Python `"""` start at the same index as a normal string.
+        if string_block_line_syntax.start_indexof != usize::MAX
+            && string_block_line_syntax.start_indexof <= string_indexof_1
+            && string_block_line_syntax.start_indexof <= string_indexof_2
         {
-            return Code::new_block(
+            let prefix = lang
+                .get_string_prefix(&line, string_block_line_syntax.start_indexof)
+                .to_owned();
+            let interpolation =
+                lang.get_string_interpolation(&prefix, &string_block_line_syntax.start_delimiter);
+
+            return Code::new(
                 hash,
                 line,
                 code_line,
                 CodeType::String,
-                &string_block_line_syntax,
+                CodeLineDelimiters {
+                    prefix: &prefix,
+                    start_delimiter: &string_block_line_syntax.start_delimiter,
+                    end_delimiter: &string_block_line_syntax.end_delimiter,
+                    ignore_by_delimiters: &[DelimiterType::None, DelimiterType::None],
+                    interpolation,
+                },
             );
         }
 
         if string_indexof_1 < string_indexof_2 {
-            return Code::new_string(hash, line, code_line, string_syntax_1);
+            return Code::new_string(
+                hash,
+                line,
+                code_line,
+                string_syntax_1,
+                string_indexof_1,
+                lang,
+            );
         }
 
         if string_indexof_2 != usize::MAX {
-            return Code::new_string(hash, line, code_line, string_syntax_2);
+            return Code::new_string(
+                hash,
+                line,
+                code_line,
+                string_syntax_2,
+                string_indexof_2,
+                lang,
+            );
         }
 
         return CodeLineState::Done(line);
@@ -407,11 +824,15 @@ impl Code {
         hash: u64,
         line: String,
         code_line: CodeLine,
-        comment_delimiter: &str,
+        lang: &'static ProgrammingLanguage,
     ) -> CodeLineState {
-        let comment_split = line.split_once(comment_delimiter);
+        let comment_split = line.split_once(lang.comment_delimiter);
 
         return match comment_split {
+            // INFO: Disabled code after the code, like `value = 1 -- value = 2`, is skipped.
+            Some((left, right)) if lang.is_commented_out_code(right) => {
+                CodeLineState::Continue(left.to_owned())
+            }
             Some((left, right)) => CodeLineState::ContinueWithResult(
                 left.to_owned(),
                 Code {
@@ -419,6 +840,9 @@ impl Code {
                     value: right.trim().to_owned(),
                     line: code_line,
                     tp: CodeType::Comment,
+                    column: line.len() - right.trim_start().len(),
+                    placeholders: Vec::new(),
+                    string_context: StringContext::Unknown,
                 },
             ),
             None => CodeLineState::Continue(line),
@@ -430,15 +854,25 @@ impl Code {
         line: String,
         code_line: CodeLine,
         string_syntax: &ProgrammingStringSyntax,
+        string_indexof: usize,
+        lang: &'static ProgrammingLanguage,
     ) -> CodeLineState {
+        let prefix = lang.get_string_prefix(&line, string_indexof).to_owned();
+        let string_delimiter = BlockDelimiter::Syntax(string_syntax.string_delimiter);
+        let interpolation = lang.get_string_interpolation(&prefix, &string_delimiter);
+
         return Code::new(
             hash,
             line,
             code_line,
             CodeType::String,
-            &string_syntax.string_delimiter,
-            &string_syntax.string_delimiter,
-            &string_syntax.string_ignore_delimiter,
+            CodeLineDelimiters {
+                prefix: &prefix,
+                start_delimiter: &string_delimiter,
+                end_delimiter: &string_delimiter,
+                ignore_by_delimiters: &string_syntax.string_ignore_delimiter,
+                interpolation,
+            },
         );
     }
 
@@ -449,76 +883,175 @@ impl Code {
         code_type: CodeType,
         block_line_syntax: &CodeBlockLineSyntax,
     ) -> CodeLineState {
+        let line = match Code::mask_nested_block(&line, block_line_syntax) {
+            Some(masked_line) => masked_line,
+            None => line,
+        };
+
         return Code::new(
             hash,
             line,
             code_line,
             code_type,
-            &block_line_syntax.start_delimiter,
-            &block_line_syntax.end_delimiter,
-            &[DelimiterType::None, DelimiterType::None],
+            CodeLineDelimiters {
+                prefix: "",
+                start_delimiter: &block_line_syntax.start_delimiter,
+                end_delimiter: &block_line_syntax.end_delimiter,
+                ignore_by_delimiters: &[DelimiterType::None, DelimiterType::None],
+                interpolation: None,
+            },
         );
     }
 
+    // INFO: Mask the delimiters of nested blocks, so the first end delimiter closes the block.
+    fn mask_nested_block(line: &str, block_line_syntax: &CodeBlockLineSyntax) -> Option<String> {
+        if !block_line_syntax.is_nested {
+            return None;
+        }
+
+        let content_start =
+            block_line_syntax.start_indexof + block_line_syntax.start_delimiter.len();
+        let block_len = block_line_syntax.find_end(line.get(content_start..)?, &mut 1)?;
+        let content_end = content_start + block_len - block_line_syntax.end_delimiter.len();
+
+        return Some(format!(
+            "{}{}{}",
+            &line[..content_start],
+            block_line_syntax.mask_nested_delimiters(&line[content_start..content_end], false),
+            &line[content_end..]
+        ));
+    }
+
     fn new(
         hash: u64,
         line: String,
         code_line: CodeLine,
         code_type: CodeType,
-        start_delimiter: &DelimiterType,
-        end_delimiter: &DelimiterType,
-        ignore_by_delimiters: &[DelimiterType; 2],
+        delimiters: CodeLineDelimiters,
     ) -> CodeLineState {
+        let CodeLineDelimiters {
+            prefix,
+            start_delimiter,
+            end_delimiter,
+            ignore_by_delimiters,
+            interpolation,
+        } = delimiters;
         let mut string_slice: Option<&str> = None;
 
-        if start_delimiter == end_delimiter {
-            let string_slices: [Option<&str>; 1] =
-                line.slices_by(start_delimiter, ignore_by_delimiters);
+        match (start_delimiter, end_delimiter) {
+            (BlockDelimiter::Syntax(start), BlockDelimiter::Syntax(end)) if start == end => {
+                let string_slices: [Option<&str>; 1] = line.slices_by(start, ignore_by_delimiters);
 
-            string_slice = string_slices[0];
-        } else {
-            string_slice = line.delimiter_slice_between(start_delimiter, end_delimiter);
+                string_slice = string_slices[0];
+            }
+            (BlockDelimiter::Syntax(start), BlockDelimiter::Syntax(end)) => {
+                string_slice = line.delimiter_slice_between(start, end);
+            }
+            _ => {
+                if let (Some(start), Some(end)) = (start_delimiter.as_str(), end_delimiter.as_str())
+                {
+                    string_slice = Some(line.slice_between(start, end));
+                }
+            }
         }
 
         // TODO: Split string by naming convention. Ignore strings with code in it.
         return match string_slice {
-            Some(mut value) => {
+            Some(value) => {
                 let mut replace_value = match start_delimiter {
-                    DelimiterType::DelimiterStr(s) => format!("{}{}", s, value),
-                    DelimiterType::DelimiterChar(c) => format!("{}{}", c, value),
-                    DelimiterType::None => String::new(),
+                    BlockDelimiter::Syntax(DelimiterType::DelimiterChar(c)) => {
+                        format!("{}{}{}", prefix, c, value)
+                    }
+                    _ => match start_delimiter.as_str() {
+                        Some(s) => format!("{}{}{}", prefix, s, value),
+                        None => String::new(),
+                    },
                 };
 
                 replace_value = match end_delimiter {
-                    DelimiterType::DelimiterStr(s) => format!("{}{}", replace_value, s),
-                    DelimiterType::DelimiterChar(c) => format!("{}{}", replace_value, c),
-                    DelimiterType::None => String::new(),
+                    BlockDelimiter::Syntax(DelimiterType::DelimiterChar(c)) => {
+                        format!("{}{}", replace_value, c)
+                    }
+                    _ => match end_delimiter.as_str() {
+                        Some(s) => format!("{}{}", replace_value, s),
+                        None => String::new(),
+                    },
+                };
+
+                // INFO: Interpolated code is put back in the line so it is handled as code.
+                let (value, replace_with) = match interpolation {
+                    Some(interpolation) => {
+                        let (prose, code) = interpolation.split_code(value);
+                        (prose, format!(" {} ", code))
+                    }
+                    None => (value.to_owned(), String::new()),
                 };
 
-                value = value.trim();
+                let replace_indexof = line
+                    .find(&replace_value)
+                    .filter(|_| !replace_value.is_empty());
+                let value_indexof = match replace_indexof {
+                    Some(indexof) => indexof + prefix.len() + start_delimiter.len(),
+                    None => 0,
+                };
+                let string_context = match (&code_type, replace_indexof) {
+                    (CodeType::String, Some(indexof)) => string_context::classify(
+                        &line[..indexof],
+                        &line[indexof + replace_value.len()..],
+                    ),
+                    _ => StringContext::Unknown,
+                };
+                let column = value_indexof + value.len() - value.trim_start().len();
+                let line = Code::mask_in_line(&line, &replace_value, &replace_with);
+                let value = value.trim();
 
                 if value.is_empty() {
-                    return CodeLineState::Continue(line.replace(&replace_value, ""));
+                    return CodeLineState::Continue(line);
                 }
 
                 // INFO: This will ignore two char blocks
                 if value.len() <= 2 {
-                    return CodeLineState::Continue(line.replace(&replace_value, ""));
+                    return CodeLineState::Continue(line);
+                }
+
+                // INFO: Ignore strings that only contain interpolations.
+                if !value.chars().any(|c| c.is_alphabetic()) {
+                    return CodeLineState::Continue(line);
                 }
 
                 return CodeLineState::ContinueWithResult(
-                    line.replace(&replace_value, ""),
+                    line,
                     Code {
                         hash,
                         value: value.to_owned(),
                         line: code_line,
                         tp: code_type,
+                        column,
+                        placeholders: Vec::new(),
+                        string_context,
                     },
                 );
             }
             None => CodeLineState::Continue(line),
         };
     }
+
+    // INFO: Replace the first `value` in the line with `replace_with`, padded with spaces to the
+    // same length, so the columns of the rest of the line are kept and every block or string on
+    // the line is its own code.
+    fn mask_in_line(line: &str, value: &str, replace_with: &str) -> String {
+        if value.is_empty() {
+            return line.to_owned();
+        }
+
+        let mut replace_with = replace_with.to_owned();
+
+        if replace_with.len() < value.len() {
+            replace_with.push_str(&" ".repeat(value.len() - replace_with.len()));
+        }
+
+        return line.replacen(value, &replace_with, 1);
+    }
 }
 
 #[derive(Debug)]
@@ -528,6 +1061,16 @@ enum CodeLineState {
     Done(String),
 }
 
+// INFO: Delimiters of a string or block in a line, like the `f` prefix and `"` of a Python
+// f-string.
+struct CodeLineDelimiters<'d> {
+    prefix: &'d str,
+    start_delimiter: &'d BlockDelimiter,
+    end_delimiter: &'d BlockDelimiter,
+    ignore_by_delimiters: &'d [DelimiterType; 2],
+    interpolation: Option<&'d StringInterpolationSyntax>,
+}
+
 #[derive(Debug, Clone)]
 pub struct CodeLine {
     // TODO: This functionality does not exit yet.
@@ -550,7 +1093,7 @@ impl CodeLine {
 }
 
 #[derive(Debug)]
-enum BlockType {
+pub enum BlockType {
     String,
     Comment,
 }
@@ -0,0 +1,117 @@
+// INFO: Chars that are common in code and rare in prose. Quotes, `#` and `@` are left out, they
+// are common in prose like `"mode"`, `#1` or `@name`.
+const CODE_CHARS: &str = "(){}[]<>=;&|*/\\%^~$";
+
+// INFO: Statements that are more often code than prose after a keyword, like `let value = 1`.
+const KEYWORD_CODE_TOKENS: [&str; 7] = ["(", "=", "{", "[", "::", "->", ";"];
+
+// INFO: Comment that is disabled code, like `// debug!("CODE: {:#?}", value);`. A comment is code
+// when it ends like a statement or block, when it starts with a call or assignment, when it starts
+// with a reserved keyword followed by code, or when it has a lot of code chars.
+pub fn is_code(comment: &str, reserved_keywords: &[&str]) -> bool {
+    let comment = comment.trim();
+
+    if !comment.chars().any(|c| c.is_alphanumeric()) {
+        return false;
+    }
+
+    if comment.ends_with(';') || comment.ends_with('{') {
+        return true;
+    }
+
+    let path_end = get_path_end(comment);
+    let rest = &comment[path_end..];
+
+    // INFO: Call like `print(value)` or `debug!(...)`, the call has no space before `(`.
+    if path_end > 0 && rest.starts_with('(') && comment.ends_with(')') {
+        return true;
+    }
+
+    // INFO: Assignment like `value = 1` or `count += 1`.
+    let rest = rest.trim_start();
+    if path_end > 0
+        && ["=", "+=", "-=", ":="]
+            .iter()
+            .any(|assignment| rest.starts_with(assignment))
+    {
+        return true;
+    }
+
+    if is_keyword_statement(comment, reserved_keywords) {
+        return true;
+    }
+
+    // INFO: Inline code in prose, like `value`, is not counted.
+    let mut is_inline_code = false;
+    let mut char_count = 0;
+    let mut code_char_count = 0;
+
+    for c in comment.chars() {
+        if c == '`' {
+            is_inline_code = !is_inline_code;
+            continue;
+        }
+
+        if !c.is_whitespace() {
+            char_count += 1;
+        }
+
+        if !is_inline_code && CODE_CHARS.contains(c) {
+            code_char_count += 1;
+        }
+    }
+
+    return code_char_count >= 3 && code_char_count * 100 >= char_count * 15;
+}
+
+// INFO: Statement like `let value = 1`, `fn main() {` or `pub fn value() -> usize`. The code
+// token has to follow the keywords or their first operand, `if the value is empty (or missing)`
+// is prose.
+fn is_keyword_statement(comment: &str, reserved_keywords: &[&str]) -> bool {
+    let mut rest = comment;
+    let mut keyword_count = 0;
+
+    loop {
+        let word_end = get_word_end(rest);
+        if word_end == 0 || !reserved_keywords.contains(&&rest[..word_end]) {
+            break;
+        }
+
+        keyword_count += 1;
+        rest = rest[word_end..].trim_start();
+    }
+
+    if keyword_count == 0 {
+        return false;
+    }
+
+    if !starts_with_code_token(rest) {
+        rest = rest[get_word_end(rest)..].trim_start();
+    }
+
+    return starts_with_code_token(rest);
+}
+
+fn get_word_end(comment: &str) -> usize {
+    return comment
+        .find(|c: char| !c.is_alphanumeric() && c != '_')
+        .unwrap_or(comment.len());
+}
+
+fn starts_with_code_token(rest: &str) -> bool {
+    return KEYWORD_CODE_TOKENS
+        .iter()
+        .any(|token| rest.starts_with(token));
+}
+
+// INFO: End of a path like `self.value`, `module::function` or a macro name like `debug!`.
+fn get_path_end(comment: &str) -> usize {
+    let path_end = comment
+        .find(|c: char| !c.is_alphanumeric() && !"_.:!".contains(c))
+        .unwrap_or(comment.len());
+
+    return match comment[..path_end].chars().next() {
+        Some(c) if c.is_alphabetic() || c == '_' => path_end,
+        _ => 0,
+    };
+}
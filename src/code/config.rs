@@ -0,0 +1,531 @@
+use super::{markdown::push_prose, prose::Prose};
+
+pub const TOML_EXTENSIONS: [&str; 1] = [".toml"];
+pub const YAML_EXTENSIONS: [&str; 2] = [".yaml", ".yml"];
+pub const JSON_EXTENSIONS: [&str; 2] = [".json", ".jsonc"];
+
+// INFO: Values of these keys are commands or references, like `run: cargo test` or
+// `uses: actions/checkout@v4`.
+const YAML_COMMAND_KEYS: [&str; 6] = ["run", "uses", "shell", "script", "command", "image"];
+
+pub fn is_toml_file(file_path: &str) -> bool {
+    return TOML_EXTENSIONS
+        .iter()
+        .any(|extension| file_path.ends_with(extension));
+}
+
+pub fn is_yaml_file(file_path: &str) -> bool {
+    return YAML_EXTENSIONS
+        .iter()
+        .any(|extension| file_path.ends_with(extension));
+}
+
+pub fn is_json_file(file_path: &str) -> bool {
+    return JSON_EXTENSIONS
+        .iter()
+        .any(|extension| file_path.ends_with(extension));
+}
+
+// INFO: Comments and string values of a TOML file. Consecutive comment lines are one prose,
+// keys and table headers are skipped.
+pub fn generate_toml(lines: &[String]) -> Vec<Prose> {
+    let mut proses = Vec::new();
+    let mut comment = Prose::new();
+    let mut multi_line_string: Option<(&'static str, Prose)> = None;
+    let mut array_level = 0;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        let mut start = 0;
+
+        if let Some((delimiter, mut value)) = multi_line_string.take() {
+            match line.find(delimiter) {
+                Some(end) => {
+                    value.push_separator();
+                    push_trimmed(&mut value, line, line_number, 0, end);
+                    push_value(&mut proses, value);
+                    start = end + delimiter.len();
+                }
+                None => {
+                    let end = line.trim_end().trim_end_matches('\\').len();
+                    value.push_separator();
+                    push_trimmed(&mut value, line, line_number, 0, end);
+                    multi_line_string = Some((delimiter, value));
+                    continue;
+                }
+            }
+        }
+
+        let trimmed = line.trim_start();
+
+        if start == 0 && trimmed.starts_with('#') {
+            comment.push_separator();
+            push_trimmed(
+                &mut comment,
+                line,
+                line_number,
+                line.len() - trimmed.len() + 1,
+                line.len(),
+            );
+            continue;
+        }
+
+        push_prose(&mut proses, &mut comment);
+
+        if start == 0 && array_level == 0 {
+            // INFO: Table header, like `[package]` or `[[bin]]`.
+            if trimmed.starts_with('[') {
+                start = match line.rfind(']') {
+                    Some(end) => end + 1,
+                    None => line.len(),
+                };
+            } else if let Some(key_end) = find_outside_of_string(line, 0, b'=') {
+                start = key_end + 1;
+            }
+        }
+
+        let bytes = line.as_bytes();
+
+        while start < line.len() {
+            match bytes[start] {
+                b'"' | b'\'' => {
+                    let quote = bytes[start];
+                    let delimiter = if quote == b'"' { "\"\"\"" } else { "'''" };
+
+                    if line[start..].starts_with(delimiter) {
+                        let value_start = start + delimiter.len();
+                        let mut value = Prose::new();
+
+                        match line[value_start..].find(delimiter) {
+                            Some(end) => {
+                                push_trimmed(
+                                    &mut value,
+                                    line,
+                                    line_number,
+                                    value_start,
+                                    value_start + end,
+                                );
+                                push_value(&mut proses, value);
+                                start = value_start + end + delimiter.len();
+                            }
+                            None => {
+                                let end = line.trim_end().trim_end_matches('\\').len();
+                                push_trimmed(&mut value, line, line_number, value_start, end);
+                                multi_line_string = Some((delimiter, value));
+                                break;
+                            }
+                        }
+                        continue;
+                    }
+
+                    let end = find_string_end(line, start + 1, quote, quote == b'"');
+                    push_value_str(&mut proses, line, line_number, start + 1, end);
+                    start = end + 1;
+                }
+                b'[' => {
+                    array_level += 1;
+                    start += 1;
+                }
+                b']' => {
+                    array_level = (array_level - 1).max(0);
+                    start += 1;
+                }
+                b'#' => {
+                    push_comment(&mut proses, line, line_number, start + 1);
+                    break;
+                }
+                _ => start += 1,
+            }
+        }
+    }
+
+    push_prose(&mut proses, &mut comment);
+
+    return proses;
+}
+
+// INFO: Comments and scalar values of a YAML file. Block scalars like `|` and `>` are one prose,
+// keys, anchors, aliases, tags and unquoted values in flow collections are skipped. Values of
+// command keys and unquoted values without whitespace, like `ubuntu-latest`, are not prose.
+pub fn generate_yaml(lines: &[String]) -> Vec<Prose> {
+    let mut proses = Vec::new();
+    let mut comment = Prose::new();
+    let mut block_scalar: Option<(usize, bool, Prose)> = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+
+        if let Some((parent_indent, is_command, mut value)) = block_scalar.take() {
+            if trimmed.is_empty() {
+                push_value(&mut proses, value);
+                block_scalar = Some((parent_indent, is_command, Prose::new()));
+                continue;
+            }
+
+            if parent_indent < indent {
+                if !is_command {
+                    value.push_separator();
+                    push_trimmed(&mut value, line, line_number, indent, line.len());
+                }
+
+                block_scalar = Some((parent_indent, is_command, value));
+                continue;
+            }
+
+            push_value(&mut proses, value);
+        }
+
+        if trimmed.starts_with('#') {
+            comment.push_separator();
+            push_trimmed(&mut comment, line, line_number, indent + 1, line.len());
+            continue;
+        }
+
+        push_prose(&mut proses, &mut comment);
+
+        if trimmed.is_empty()
+            || trimmed.starts_with("---")
+            || trimmed.starts_with("...")
+            || trimmed.starts_with('%')
+        {
+            continue;
+        }
+
+        let bytes = line.as_bytes();
+        let mut start = indent;
+
+        // INFO: Sequence entries, like `- name: value` or `- - value`.
+        while line[start..].starts_with("- ") || &line[start..] == "-" {
+            start = skip_whitespace(line, start + 1);
+        }
+
+        let mut is_command = false;
+
+        if let Some(key_end) = find_yaml_key_end(line, start) {
+            let key = line[start..key_end].trim().trim_matches(['"', '\'']);
+            is_command = YAML_COMMAND_KEYS.contains(&key);
+            start = skip_whitespace(line, key_end + 1);
+        }
+
+        // INFO: Anchors, aliases and tags in front of the value.
+        while start < line.len() && matches!(bytes[start], b'&' | b'*' | b'!') {
+            start = match line[start..].find(' ') {
+                Some(end) => skip_whitespace(line, start + end),
+                None => line.len(),
+            };
+        }
+
+        if line.len() <= start {
+            continue;
+        }
+
+        match bytes[start] {
+            b'#' => push_comment(&mut proses, line, line_number, start + 1),
+            b'"' | b'\'' => {
+                let quote = bytes[start];
+                let end = find_string_end(line, start + 1, quote, quote == b'"');
+
+                if !is_command {
+                    push_value_str(&mut proses, line, line_number, start + 1, end);
+                }
+
+                if let Some(comment_start) = line[end..].find(" #") {
+                    push_comment(&mut proses, line, line_number, end + comment_start + 2);
+                }
+            }
+            b'|' | b'>' => {
+                block_scalar = Some((indent, is_command, Prose::new()));
+
+                if let Some(comment_start) = line[start..].find(" #") {
+                    push_comment(&mut proses, line, line_number, start + comment_start + 2);
+                }
+            }
+            b'[' | b'{' => {
+                let mut flow_start = start;
+
+                while flow_start < line.len() {
+                    match bytes[flow_start] {
+                        b'"' | b'\'' => {
+                            let quote = bytes[flow_start];
+                            let end = find_string_end(line, flow_start + 1, quote, quote == b'"');
+                            push_value_str(&mut proses, line, line_number, flow_start + 1, end);
+                            flow_start = end + 1;
+                        }
+                        b'#' if bytes[flow_start - 1] == b' ' => {
+                            push_comment(&mut proses, line, line_number, flow_start + 1);
+                            break;
+                        }
+                        _ => flow_start += 1,
+                    }
+                }
+            }
+            _ => {
+                let end = match line[start..].find(" #") {
+                    Some(comment_start) => {
+                        push_comment(&mut proses, line, line_number, start + comment_start + 2);
+                        start + comment_start
+                    }
+                    None => line.len(),
+                };
+
+                if !is_command && line[start..end].trim().contains(char::is_whitespace) {
+                    push_value_str(&mut proses, line, line_number, start, end);
+                }
+            }
+        }
+    }
+
+    if let Some((_, _, value)) = block_scalar {
+        push_value(&mut proses, value);
+    }
+
+    push_prose(&mut proses, &mut comment);
+
+    return proses;
+}
+
+// INFO: Comments and string values of a JSON file. Comments are only valid in JSONC, but files
+// like `tsconfig.json` use them too. Keys are skipped.
+pub fn generate_json(lines: &[String]) -> Vec<Prose> {
+    let mut proses = Vec::new();
+    let mut comment = Prose::new();
+    let mut block_comment: Option<Prose> = None;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        let bytes = line.as_bytes();
+        let mut start = 0;
+
+        if let Some(mut value) = block_comment.take() {
+            let line_start = line.len() - line.trim_start_matches([' ', '\t', '*']).len();
+
+            match line.find("*/") {
+                Some(end) => {
+                    value.push_separator();
+                    push_trimmed(&mut value, line, line_number, line_start.min(end), end);
+                    push_value(&mut proses, value);
+                    start = end + 2;
+                }
+                None => {
+                    value.push_separator();
+                    push_trimmed(&mut value, line, line_number, line_start, line.len());
+                    block_comment = Some(value);
+                    continue;
+                }
+            }
+        }
+
+        let trimmed = line.trim_start();
+
+        if start == 0 && trimmed.starts_with("//") {
+            comment.push_separator();
+            push_trimmed(
+                &mut comment,
+                line,
+                line_number,
+                line.len() - trimmed.len() + 2,
+                line.len(),
+            );
+            continue;
+        }
+
+        push_prose(&mut proses, &mut comment);
+
+        while start < line.len() {
+            match bytes[start] {
+                b'"' => {
+                    let end = find_string_end(line, start + 1, b'"', true);
+
+                    // INFO: A string followed by a colon is a key.
+                    if !line[(end + 1).min(line.len())..]
+                        .trim_start()
+                        .starts_with(':')
+                    {
+                        push_value_str(&mut proses, line, line_number, start + 1, end);
+                    }
+
+                    start = end + 1;
+                }
+                b'/' if line[start..].starts_with("//") => {
+                    push_comment(&mut proses, line, line_number, start + 2);
+                    break;
+                }
+                b'/' if line[start..].starts_with("/*") => {
+                    let mut value = Prose::new();
+
+                    match line[start + 2..].find("*/") {
+                        Some(end) => {
+                            push_trimmed(&mut value, line, line_number, start + 2, start + 2 + end);
+                            push_value(&mut proses, value);
+                            start = start + 2 + end + 2;
+                        }
+                        None => {
+                            push_trimmed(&mut value, line, line_number, start + 2, line.len());
+                            block_comment = Some(value);
+                            break;
+                        }
+                    }
+                }
+                _ => start += 1,
+            }
+        }
+    }
+
+    if let Some(value) = block_comment {
+        push_value(&mut proses, value);
+    }
+
+    push_prose(&mut proses, &mut comment);
+
+    return proses;
+}
+
+// INFO: Booleans, numbers, dates and URL-looking values are not prose.
+pub fn is_prose_value(value: &str) -> bool {
+    let value = value.trim();
+
+    if !value.chars().any(|c| c.is_alphabetic()) {
+        return false;
+    }
+
+    if matches!(
+        value.to_lowercase().as_str(),
+        "true" | "false" | "yes" | "no" | "on" | "off" | "null" | "inf" | "nan"
+    ) {
+        return false;
+    }
+
+    if value.replace('_', "").parse::<f64>().is_ok()
+        || value.starts_with("0x")
+        || value.starts_with("0o")
+        || value.starts_with("0b")
+    {
+        return false;
+    }
+
+    // INFO: Dates and times, like `1979-05-27T07:32:00Z`.
+    if value
+        .chars()
+        .all(|c| c.is_ascii_digit() || matches!(c, '-' | ':' | '.' | '+' | 'T' | 'Z' | ' '))
+    {
+        return false;
+    }
+
+    if value.contains("://") || value.starts_with("www.") {
+        return false;
+    }
+
+    // INFO: Email, like `name@example.com`.
+    if !value.contains(' ') && value.contains('@') && value.contains('.') {
+        return false;
+    }
+
+    return true;
+}
+
+fn push_value(proses: &mut Vec<Prose>, value: Prose) {
+    if !is_prose_value(&value.value) {
+        return;
+    }
+
+    proses.push(value.finish());
+}
+
+fn push_value_str(
+    proses: &mut Vec<Prose>,
+    line: &str,
+    line_number: usize,
+    start: usize,
+    end: usize,
+) {
+    let mut value = Prose::new();
+    push_trimmed(&mut value, line, line_number, start, end);
+    push_value(proses, value);
+}
+
+fn push_comment(proses: &mut Vec<Prose>, line: &str, line_number: usize, start: usize) {
+    let mut comment = Prose::new();
+    push_trimmed(&mut comment, line, line_number, start, line.len());
+    push_prose(proses, &mut comment);
+}
+
+// INFO: Push the value between start and end without the surrounding whitespace.
+fn push_trimmed(prose: &mut Prose, line: &str, line_number: usize, start: usize, end: usize) {
+    if end <= start {
+        return;
+    }
+
+    let value = &line[start..end];
+    let value_start = start + (value.len() - value.trim_start().len());
+    prose.push_str(value.trim(), line_number, value_start);
+}
+
+fn skip_whitespace(line: &str, start: usize) -> usize {
+    return start + (line[start..].len() - line[start..].trim_start().len());
+}
+
+// INFO: Index of the closing quote, or the end of the line when the string is not closed.
+fn find_string_end(line: &str, start: usize, quote: u8, has_escape: bool) -> usize {
+    let bytes = line.as_bytes();
+    let mut index = start;
+
+    while index < bytes.len() {
+        if has_escape && bytes[index] == b'\\' {
+            index += 2;
+            continue;
+        }
+
+        if bytes[index] == quote {
+            // INFO: YAML single quoted strings escape a quote with two quotes.
+            if !has_escape && index + 1 < bytes.len() && bytes[index + 1] == quote {
+                index += 2;
+                continue;
+            }
+
+            return index;
+        }
+
+        index += 1;
+    }
+
+    return line.len();
+}
+
+fn find_outside_of_string(line: &str, start: usize, c: u8) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut index = start;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'"' | b'\'' => {
+                index = find_string_end(line, index + 1, bytes[index], bytes[index] == b'"') + 1
+            }
+            b'#' => return None,
+            current if current == c => return Some(index),
+            _ => index += 1,
+        }
+    }
+
+    return None;
+}
+
+// INFO: The colon of a mapping key is followed by a space or the end of the line, so URLs and
+// times in a value are not keys.
+fn find_yaml_key_end(line: &str, start: usize) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut index = start;
+
+    while index < bytes.len() {
+        let key_end = find_outside_of_string(line, index, b':')?;
+
+        if key_end + 1 == bytes.len() || bytes[key_end + 1] == b' ' {
+            return Some(key_end);
+        }
+
+        index = key_end + 1;
+    }
+
+    return None;
+}
@@ -0,0 +1,365 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+    sync::{OnceLock, RwLock},
+};
+
+use home::home_dir;
+use log::{error, info, warn};
+use serde::Deserialize;
+
+use crate::common::string::{intern_str, DelimiterType};
+
+use super::programming::{
+    CodeBlockSyntax, FormatPlaceholderSyntax, NamingConvetionType, ProgrammingLanguage,
+    ProgrammingLanguageType, ProgrammingStringSyntax, PROGRAMMING_LANGUAGES,
+};
+
+// INFO: Languages loaded from the language definition files. They are leaked, so they can be used
+// like the built-in languages, and are checked before them.
+static LANGUAGE_DEFINITIONS: RwLock<Vec<&'static ProgrammingLanguage>> = RwLock::new(Vec::new());
+
+// INFO: Languages built from a Neovim `commentstring`, leaked once per `commentstring`.
+static COMMENTSTRING_LANGUAGES: RwLock<Vec<(&'static str, &'static ProgrammingLanguage)>> =
+    RwLock::new(Vec::new());
+
+// INFO: Count of definitions loaded from the config directory, they are only loaded and leaked
+// once, even when the core is created again.
+static DEFAULT_LANGUAGE_DEFINITIONS: OnceLock<usize> = OnceLock::new();
+
+// INFO: A language definition file, like `~/.config/nvim/nvim_language/languages/zig.json`.
+//
+// {
+//     "extension": ".zig",
+//     "filetype": "zig",
+//     "comment_delimiter": "//",
+//     "block_comment": [],
+//     "block_string": [],
+//     "strings": [{ "delimiter": "\"", "escapes": ["\\\""] }],
+//     "operators": ["=", "(", ")", "{", "}", ";"],
+//     "reserved_keywords": ["const", "fn", "pub", "return"],
+//     "naming_conventions": ["camel_case", "pascal_case"],
+//     "format_placeholders": ["brace"]
+// }
+#[derive(Debug, Deserialize)]
+pub struct LanguageDefinition {
+    pub extension: String,
+    #[serde(default)]
+    pub filetype: String,
+    pub comment_delimiter: String,
+    #[serde(default)]
+    pub block_comment: Vec<[String; 2]>,
+    #[serde(default)]
+    pub block_string: Vec<[String; 2]>,
+    #[serde(default)]
+    pub strings: Vec<StringDefinition>,
+    #[serde(default)]
+    pub string_prefixes: String,
+    #[serde(default)]
+    pub operators: Vec<String>,
+    #[serde(default)]
+    pub reserved_keywords: Vec<String>,
+    #[serde(default)]
+    pub naming_conventions: Vec<String>,
+    #[serde(default)]
+    pub format_placeholders: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct StringDefinition {
+    pub delimiter: String,
+    #[serde(default)]
+    pub escapes: Vec<String>,
+}
+
+impl LanguageDefinition {
+    pub fn to_programming_language(&self) -> Result<ProgrammingLanguage, String> {
+        if !self.extension.starts_with('.') || self.extension.len() < 2 {
+            return Err(format!("invalid extension '{}'", self.extension));
+        }
+
+        if self.comment_delimiter.is_empty() {
+            return Err("comment_delimiter can not be empty".to_owned());
+        }
+
+        if 4 < self.block_comment.len() || 4 < self.block_string.len() {
+            return Err("no more than four block delimiters are supported".to_owned());
+        }
+
+        if 2 < self.strings.len() || self.strings.iter().any(|s| 2 < s.escapes.len()) {
+            return Err("no more than two strings with two escapes are supported".to_owned());
+        }
+
+        if 2 < self.naming_conventions.len() {
+            return Err("no more than two naming conventions are supported".to_owned());
+        }
+
+        let mut string_syntax = [
+            ProgrammingStringSyntax::default(),
+            ProgrammingStringSyntax::default(),
+        ];
+
+        for (index, string) in self.strings.iter().enumerate() {
+            string_syntax[index].string_delimiter = to_delimiter(&string.delimiter);
+
+            for (escape_index, escape) in string.escapes.iter().enumerate() {
+                string_syntax[index].string_ignore_delimiter[escape_index] = to_delimiter(escape);
+            }
+        }
+
+        let mut naming_conventions = [NamingConvetionType::None, NamingConvetionType::None];
+
+        for (index, naming_convention) in self.naming_conventions.iter().enumerate() {
+            naming_conventions[index] = match naming_convention.as_str() {
+                "camel_case" => NamingConvetionType::CamelCase,
+                "pascal_case" => NamingConvetionType::PascalCase,
+                "none" => NamingConvetionType::None,
+                _ => return Err(format!("unknown naming convention '{}'", naming_convention)),
+            };
+        }
+
+        return Ok(ProgrammingLanguage {
+            extension: intern_str(&self.extension),
+            filetype: intern_str(&self.filetype),
+            comment_delimiter: intern_str(&self.comment_delimiter),
+            block_comment: to_code_block_syntax(&self.block_comment),
+            block_comment_line_prefix: None,
+            doc_comment_delimiters: &[],
+            doc_comment_annotation: None,
+            preprocessor_directive: None,
+            operators_and_syntax: to_static_slice(&self.operators),
+            reserved_keywords: to_static_slice(&self.reserved_keywords),
+            string_syntax,
+            string_prefixes: intern_str(&self.string_prefixes),
+            string_interpolation: None,
+            format_placeholders: to_format_placeholders(&self.format_placeholders)?,
+            block_string: to_code_block_syntax(&self.block_string),
+            naming_conventions,
+            lang_type: ProgrammingLanguageType::Custom,
+            code_literal_mask: None,
+            code_prose: None,
+        });
+    }
+}
+
+// INFO: `$XDG_CONFIG_HOME/nvim/nvim_language/languages`, or `~/.config/nvim/...` by default.
+pub fn get_language_definitions_dir() -> Option<PathBuf> {
+    let mut config_dir = match env::var_os("XDG_CONFIG_HOME") {
+        Some(config_dir) if !config_dir.is_empty() => PathBuf::from(config_dir),
+        _ => {
+            let mut home_dir = home_dir()?;
+            home_dir.push(".config");
+            home_dir
+        }
+    };
+
+    config_dir.push("nvim/nvim_language/languages");
+
+    return Some(config_dir);
+}
+
+// INFO: Load the language definitions of the config directory, only the first call reads them.
+pub fn load_default_language_definitions() -> usize {
+    return *DEFAULT_LANGUAGE_DEFINITIONS.get_or_init(|| {
+        return match get_language_definitions_dir() {
+            Some(language_definitions_dir) => load_language_definitions(&language_definitions_dir),
+            None => 0,
+        };
+    });
+}
+
+// INFO: Load every `.json` language definition in the directory. A definition with the same
+// extension as an already loaded one replaces it. Returns the count of loaded definitions.
+// Definitions are leaked, so this should only run when the plugin starts.
+pub fn load_language_definitions(dir: &Path) -> usize {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            info!("No language definitions in {:?}, {}", dir, e);
+            return 0;
+        }
+    };
+
+    let mut count = 0;
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if path.extension().is_none_or(|extension| extension != "json") {
+            continue;
+        }
+
+        let definition = match fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|value| {
+                serde_json::from_str::<LanguageDefinition>(&value).map_err(|e| e.to_string())
+            }) {
+            Ok(definition) => definition,
+            Err(e) => {
+                error!("Unable to read language definition {:?}, {}", path, e);
+                continue;
+            }
+        };
+
+        let lang = match definition.to_programming_language() {
+            Ok(lang) => lang,
+            Err(e) => {
+                error!("Invalid language definition {:?}, {}", path, e);
+                continue;
+            }
+        };
+
+        let mut language_definitions = match LANGUAGE_DEFINITIONS.write() {
+            Ok(language_definitions) => language_definitions,
+            Err(e) => e.into_inner(),
+        };
+
+        language_definitions.retain(|loaded| loaded.extension != lang.extension);
+        language_definitions.push(Box::leak(Box::new(lang)));
+        count += 1;
+    }
+
+    info!("Loaded {} language definitions from {:?}", count, dir);
+
+    return count;
+}
+
+// INFO: Find the language of the file, languages from definition files come first so a built-in
+// language can be replaced.
+pub fn find_language(file_path: &str) -> Option<&'static ProgrammingLanguage> {
+    match LANGUAGE_DEFINITIONS.read() {
+        Ok(language_definitions) => {
+            if let Some(lang) = language_definitions
+                .iter()
+                .find(|lang| file_path.ends_with(lang.extension))
+            {
+                return Some(*lang);
+            }
+        }
+        Err(e) => warn!("Unable to read language definitions {:?}", e),
+    };
+
+    return PROGRAMMING_LANGUAGES
+        .into_iter()
+        .find(|lang| file_path.ends_with(lang.extension));
+}
+
+pub fn find_language_by_filetype(filetype: &str) -> Option<&'static ProgrammingLanguage> {
+    match LANGUAGE_DEFINITIONS.read() {
+        Ok(language_definitions) => {
+            if let Some(lang) = language_definitions
+                .iter()
+                .find(|lang| lang.filetype == filetype)
+            {
+                return Some(*lang);
+            }
+        }
+        Err(e) => warn!("Unable to read language definitions {:?}", e),
+    };
+
+    return PROGRAMMING_LANGUAGES
+        .into_iter()
+        .find(|lang| lang.filetype == filetype);
+}
+
+// INFO: Language for filetypes without a language, built from the Neovim `commentstring`, like
+// `# %s` or `<!-- %s -->`. Only the comments of the language are checked.
+pub fn find_language_by_commentstring(commentstring: &str) -> Option<&'static ProgrammingLanguage> {
+    match COMMENTSTRING_LANGUAGES.read() {
+        Ok(commentstring_languages) => {
+            for (loaded_commentstring, lang) in commentstring_languages.iter() {
+                if *loaded_commentstring == commentstring {
+                    return Some(*lang);
+                }
+            }
+        }
+        Err(e) => warn!("Unable to read commentstring languages {:?}", e),
+    };
+
+    let (start, end) = commentstring.split_once("%s")?;
+    let (start, end) = (start.trim(), end.trim());
+
+    if start.is_empty() {
+        return None;
+    }
+
+    // INFO: A `commentstring` with an end, like `/* %s */`, is a block comment. The start is also
+    // used as comment delimiter, but the block comment wins when both start at the same index.
+    let block_comment = match end.is_empty() {
+        true => CodeBlockSyntax::new(&[]),
+        false => CodeBlockSyntax::new(&[(to_delimiter(start), to_delimiter(end))]),
+    };
+
+    let lang: &'static ProgrammingLanguage = Box::leak(Box::new(ProgrammingLanguage {
+        extension: "",
+        filetype: "",
+        comment_delimiter: intern_str(start),
+        block_comment,
+        block_comment_line_prefix: None,
+        doc_comment_delimiters: &[],
+        doc_comment_annotation: None,
+        preprocessor_directive: None,
+        operators_and_syntax: &[],
+        reserved_keywords: &[],
+        string_syntax: [
+            ProgrammingStringSyntax::default(),
+            ProgrammingStringSyntax::default(),
+        ],
+        string_prefixes: "",
+        string_interpolation: None,
+        format_placeholders: &[],
+        block_string: CodeBlockSyntax::new(&[]),
+        naming_conventions: [NamingConvetionType::None, NamingConvetionType::None],
+        lang_type: ProgrammingLanguageType::CommentString,
+        code_literal_mask: None,
+        code_prose: None,
+    }));
+
+    let mut commentstring_languages = match COMMENTSTRING_LANGUAGES.write() {
+        Ok(commentstring_languages) => commentstring_languages,
+        Err(e) => e.into_inner(),
+    };
+
+    commentstring_languages.push((intern_str(commentstring), lang));
+
+    return Some(lang);
+}
+
+fn to_delimiter(value: &str) -> DelimiterType {
+    let mut chars = value.chars();
+
+    return match (chars.next(), chars.next()) {
+        (None, _) => DelimiterType::None,
+        (Some(c), None) => DelimiterType::DelimiterChar(c),
+        _ => DelimiterType::DelimiterStr(intern_str(value)),
+    };
+}
+
+fn to_code_block_syntax(delimiters: &[[String; 2]]) -> CodeBlockSyntax {
+    let delimiters: Vec<(DelimiterType, DelimiterType)> = delimiters
+        .iter()
+        .map(|[start, end]| (to_delimiter(start), to_delimiter(end)))
+        .collect();
+
+    return CodeBlockSyntax::new(&delimiters);
+}
+
+fn to_format_placeholders(values: &[String]) -> Result<&'static [FormatPlaceholderSyntax], String> {
+    let mut format_placeholders = Vec::with_capacity(values.len());
+
+    for value in values {
+        format_placeholders.push(match value.as_str() {
+            "brace" => FormatPlaceholderSyntax::Brace,
+            "printf" => FormatPlaceholderSyntax::Printf,
+            _ => return Err(format!("unknown format placeholder '{}'", value)),
+        });
+    }
+
+    return Ok(Box::leak(format_placeholders.into_boxed_slice()));
+}
+
+fn to_static_slice(values: &[String]) -> &'static [&'static str] {
+    let values: Vec<&'static str> = values.iter().map(|value| intern_str(value)).collect();
+
+    return Box::leak(values.into_boxed_slice());
+}
@@ -0,0 +1,113 @@
+use super::{markdown, prose::Prose};
+
+// INFO: A line of a doc comment without the doc comment delimiter, the column is where the
+// content starts in the original line.
+#[derive(Debug)]
+pub struct DocCommentLine {
+    pub line_number: usize,
+    pub column: usize,
+    pub content: String,
+    // INFO: Only the description of an annotation is in the content, it is its own prose.
+    pub is_annotation: bool,
+}
+
+// INFO: Consecutive doc comment lines are one Markdown document. Doctests are fenced or indented
+// code, so they are skipped like any other Markdown code. Annotations are their own document.
+pub fn generate(lines: &[DocCommentLine]) -> Vec<Prose> {
+    let mut proses = Vec::new();
+    let mut document_start = 0;
+
+    for (index, line) in lines.iter().enumerate() {
+        if line.is_annotation {
+            proses.extend(generate_document(&lines[document_start..index]));
+            proses.extend(generate_document(&lines[index..index + 1]));
+            document_start = index + 1;
+        }
+    }
+
+    proses.extend(generate_document(&lines[document_start..]));
+
+    return proses;
+}
+
+// INFO: The spans are moved back from the content to the original line.
+fn generate_document(lines: &[DocCommentLine]) -> Vec<Prose> {
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    let contents: Vec<String> = lines
+        .iter()
+        .map(|line| mask_intra_doc_links(&line.content))
+        .collect();
+
+    let mut proses = markdown::generate(&contents);
+
+    for prose in &mut proses {
+        for span in &mut prose.spans {
+            let line = &lines[span.line_number - 1];
+            span.line_number = line.line_number;
+            span.column += line.column;
+        }
+    }
+
+    return proses;
+}
+
+// INFO: Intra-doc links like [`Vec::new`] or [Option] are replaced by a code span with the same
+// length, so they are masked and the columns after them are kept. Links with a destination keep
+// their text, like any other Markdown link.
+fn mask_intra_doc_links(content: &str) -> String {
+    let mut masked = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(link_start) = rest.find('[') {
+        let link_len = match rest[link_start..].find(']') {
+            Some(link_len) => link_len + 1,
+            None => break,
+        };
+        let link_end = link_start + link_len;
+        let text = &rest[link_start + 1..link_end - 1];
+
+        masked.push_str(&rest[..link_start]);
+
+        if is_intra_doc_link(text) && !rest[link_end..].starts_with(['(', '[', ':']) {
+            masked.push('`');
+            masked.push_str(&"0".repeat(link_len - 2));
+            masked.push('`');
+        } else {
+            masked.push_str(&rest[link_start..link_end]);
+        }
+
+        rest = &rest[link_end..];
+    }
+
+    masked.push_str(rest);
+
+    return masked;
+}
+
+// INFO: A path like `Vec::new`, `fn@parse`, `vec!` or `parse_file`, or a type like `Vec`. Prose
+// in brackets like `[optional]` and a task list `[x]` are not links.
+fn is_intra_doc_link(text: &str) -> bool {
+    if 1 < text.len() && text.starts_with('`') && text.ends_with('`') {
+        return true;
+    }
+
+    if text.is_empty() || text == "x" || text == "X" {
+        return false;
+    }
+
+    if !text
+        .chars()
+        .all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '@' | '!' | '<' | '>' | '(' | ')'))
+    {
+        return false;
+    }
+
+    return text.starts_with(char::is_uppercase)
+        || text.contains("::")
+        || text.contains(['_', '@'])
+        || text.ends_with('!')
+        || text.ends_with("()");
+}
@@ -0,0 +1,244 @@
+use std::{
+    fs::File,
+    io::{Read, Seek, SeekFrom},
+};
+
+use log::{debug, error};
+
+use super::{
+    config, definition, git_message, markdown, programming::ProgrammingLanguage, prose::Prose,
+};
+
+// INFO: Lines at the start and end of the file where a modeline is searched, like Vim does.
+const MODELINE_LINE_COUNT: usize = 5;
+// INFO: Bytes read at the start and end of the file for the modeline and shebang, so a large file
+// or a file with long lines is not read as a whole.
+const MODELINE_BYTE_COUNT: u64 = 8192;
+
+pub type ProseGenerator = fn(&[String]) -> Vec<Prose>;
+
+pub enum FileLanguage {
+    Programming(&'static ProgrammingLanguage),
+    Prose(ProseGenerator),
+}
+
+// INFO: Detect the language of the file. The Neovim `filetype` wins, without it the file path, a
+// modeline and at last the shebang is used. When nothing is found, the `commentstring` is used.
+pub fn detect(
+    file_path: &str,
+    filetype: Option<&str>,
+    commentstring: Option<&str>,
+) -> Option<FileLanguage> {
+    let file_language = match filetype.filter(|filetype| !filetype.is_empty()) {
+        Some(filetype) => from_filetype(filetype),
+        None => from_file(file_path),
+    };
+
+    if file_language.is_some() {
+        return file_language;
+    }
+
+    let commentstring = commentstring?;
+    let lang = definition::find_language_by_commentstring(commentstring)?;
+    debug!(
+        "Language from commentstring '{}' of {}",
+        commentstring, file_path
+    );
+
+    return Some(FileLanguage::Programming(lang));
+}
+
+fn from_file(file_path: &str) -> Option<FileLanguage> {
+    // INFO: The file is only read when the path does not tell the language.
+    if let Some(file_language) = from_file_path(file_path) {
+        return Some(file_language);
+    }
+
+    let (head, tail) = read_head_and_tail(file_path);
+
+    if let Some(filetype) = head
+        .iter()
+        .chain(tail.iter())
+        .find_map(|line| find_modeline_filetype(line))
+    {
+        debug!("Filetype '{}' from modeline of {}", filetype, file_path);
+
+        if let Some(file_language) = from_filetype(filetype) {
+            return Some(file_language);
+        }
+    }
+
+    let filetype = find_shebang_filetype(head.first()?)?;
+    debug!("Filetype '{}' from shebang of {}", filetype, file_path);
+
+    return from_filetype(filetype);
+}
+
+// INFO: Compound filetypes like `markdown.pandoc` use the first known part.
+pub fn from_filetype(filetype: &str) -> Option<FileLanguage> {
+    for filetype in filetype.split('.') {
+        if let Some(lang) = definition::find_language_by_filetype(filetype) {
+            return Some(FileLanguage::Programming(lang));
+        }
+
+        let generate: ProseGenerator = match filetype {
+            "markdown" => markdown::generate,
+            "gitcommit" => git_message::generate,
+            "toml" => config::generate_toml,
+            "yaml" => config::generate_yaml,
+            "json" | "jsonc" => config::generate_json,
+            _ => continue,
+        };
+
+        return Some(FileLanguage::Prose(generate));
+    }
+
+    return None;
+}
+
+pub fn from_file_path(file_path: &str) -> Option<FileLanguage> {
+    if let Some(lang) = definition::find_language(file_path) {
+        return Some(FileLanguage::Programming(lang));
+    }
+
+    if markdown::is_markdown_file(file_path) {
+        return Some(FileLanguage::Prose(markdown::generate));
+    }
+
+    if git_message::is_git_message_file(file_path) {
+        return Some(FileLanguage::Prose(git_message::generate));
+    }
+
+    if config::is_toml_file(file_path) {
+        return Some(FileLanguage::Prose(config::generate_toml));
+    }
+
+    if config::is_yaml_file(file_path) {
+        return Some(FileLanguage::Prose(config::generate_yaml));
+    }
+
+    if config::is_json_file(file_path) {
+        return Some(FileLanguage::Prose(config::generate_json));
+    }
+
+    return None;
+}
+
+// INFO: Filetype of the interpreter, like `#!/usr/bin/env python3` or `#!/bin/bash -e`.
+pub fn find_shebang_filetype(line: &str) -> Option<&'static str> {
+    let mut command = line.strip_prefix("#!")?.split_whitespace();
+    let mut interpreter = command.next()?.rsplit('/').next()?;
+
+    // INFO: `env` can have options, like `#!/usr/bin/env -S deno run`.
+    if interpreter == "env" {
+        interpreter = command.find(|argument| !argument.starts_with('-'))?;
+    }
+
+    let interpreter = interpreter.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+
+    return match interpreter {
+        "sh" | "dash" | "ksh" => Some("sh"),
+        "bash" => Some("bash"),
+        "zsh" => Some("zsh"),
+        "python" => Some("python"),
+        "lua" | "luajit" | "nvim" => Some("lua"),
+        "node" | "nodejs" => Some("javascript"),
+        "deno" | "ts-node" | "bun" => Some("typescript"),
+        _ => None,
+    };
+}
+
+// INFO: Filetype of a modeline, like `vim: set ft=rust:` or `vi: filetype=lua`.
+pub fn find_modeline_filetype(line: &str) -> Option<&str> {
+    let mut search_from = 0;
+
+    while search_from < line.len() {
+        let rest = &line[search_from..];
+        let modeline_start = ["vim:", "vi:", "ex:"]
+            .iter()
+            .filter_map(|marker| rest.find(marker).map(|index| (index, marker.len())))
+            .min()?;
+        let marker_index = search_from + modeline_start.0;
+        search_from = marker_index + modeline_start.1;
+
+        // INFO: The marker has to be at the start or after whitespace, so `navi:` is not a modeline.
+        if 0 < marker_index && !line[..marker_index].ends_with(char::is_whitespace) {
+            continue;
+        }
+
+        let options = line[search_from..].trim_start();
+        let options = options.strip_prefix("set ").unwrap_or(options);
+
+        for option in options.split(|c: char| c == ':' || c.is_whitespace()) {
+            if let Some(filetype) = option
+                .strip_prefix("ft=")
+                .or_else(|| option.strip_prefix("filetype="))
+            {
+                if !filetype.is_empty() {
+                    return Some(filetype);
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+fn read_head_and_tail(file_path: &str) -> (Vec<String>, Vec<String>) {
+    let mut file = match File::open(file_path) {
+        Ok(file) => file,
+        Err(e) => {
+            error!("Unable to open file: {}, error: {}", file_path, e);
+            return (Vec::new(), Vec::new());
+        }
+    };
+
+    let mut head_bytes = Vec::new();
+
+    if let Err(e) = (&mut file)
+        .take(MODELINE_BYTE_COUNT)
+        .read_to_end(&mut head_bytes)
+    {
+        error!("Unable to read file: {}, error: {}", file_path, e);
+        return (Vec::new(), Vec::new());
+    }
+
+    let head_text = String::from_utf8_lossy(&head_bytes);
+    let head_lines: Vec<&str> = head_text.lines().collect();
+    let head = head_lines
+        .iter()
+        .take(MODELINE_LINE_COUNT)
+        .map(|line| line.to_string())
+        .collect();
+
+    let file_len = file.metadata().map_or(0, |metadata| metadata.len());
+
+    // INFO: The whole file is in the head bytes, the tail is the lines after the head.
+    if file_len <= MODELINE_BYTE_COUNT {
+        let tail_lines = &head_lines[MODELINE_LINE_COUNT.min(head_lines.len())..];
+        return (head, get_last_lines(tail_lines));
+    }
+
+    let mut tail_bytes = Vec::new();
+
+    if let Err(e) = file
+        .seek(SeekFrom::End(-(MODELINE_BYTE_COUNT as i64)))
+        .and_then(|_| file.read_to_end(&mut tail_bytes))
+    {
+        error!("Unable to read file: {}, error: {}", file_path, e);
+        return (head, Vec::new());
+    }
+
+    // INFO: The first line is cut by the seek, it is not a whole line.
+    let tail_text = String::from_utf8_lossy(&tail_bytes);
+    let tail_lines: Vec<&str> = tail_text.lines().skip(1).collect();
+
+    return (head, get_last_lines(&tail_lines));
+}
+
+fn get_last_lines(lines: &[&str]) -> Vec<String> {
+    return lines[lines.len().saturating_sub(MODELINE_LINE_COUNT)..]
+        .iter()
+        .map(|line| line.to_string())
+        .collect();
+}
@@ -0,0 +1,69 @@
+use std::path::Path;
+
+use super::{
+    markdown::{push_inline, push_prose},
+    prose::Prose,
+};
+
+pub const GIT_MESSAGE_FILES: [&str; 3] = ["COMMIT_EDITMSG", "MERGE_MSG", "TAG_EDITMSG"];
+
+// INFO: Everything after this line is removed by git, like the diff of `git commit --verbose`.
+pub const SCISSORS_LINE: &str = "# ------------------------ >8 ------------------------";
+
+pub fn is_git_message_file(file_path: &str) -> bool {
+    return match Path::new(file_path).file_name() {
+        Some(file_name) => GIT_MESSAGE_FILES.iter().any(|name| file_name == *name),
+        None => false,
+    };
+}
+
+// INFO: The subject and every paragraph of the body is its own prose. Comment lines and trailers
+// like `Signed-off-by:` are skipped.
+pub fn generate(lines: &[String]) -> Vec<Prose> {
+    let mut proses = Vec::new();
+    let mut prose = Prose::new();
+    let mut is_subject = true;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+
+        if line.starts_with(SCISSORS_LINE) {
+            break;
+        }
+
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let end = line.trim_end().len();
+
+        if end == 0 || is_trailer(line) {
+            push_prose(&mut proses, &mut prose);
+            continue;
+        }
+
+        let start = line.len() - line.trim_start().len();
+
+        prose.push_separator();
+        push_inline(&mut prose, line, line_number, start, end);
+
+        // INFO: The subject is always one line, even without a blank line after it.
+        if is_subject {
+            push_prose(&mut proses, &mut prose);
+            is_subject = false;
+        }
+    }
+
+    push_prose(&mut proses, &mut prose);
+
+    return proses;
+}
+
+fn is_trailer(line: &str) -> bool {
+    let token = match line.split_once(": ") {
+        Some((token, _)) => token,
+        None => return false,
+    };
+
+    return token.contains('-') && token.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+}
@@ -0,0 +1,68 @@
+use super::{annotation_marker, markdown::push_prose, prose::Prose};
+
+// INFO: A line that only has a line comment, like `// text`. The column is where the content
+// starts in the original line.
+#[derive(Debug)]
+pub struct LineComment {
+    pub line_number: usize,
+    pub column: usize,
+    pub content: String,
+}
+
+// INFO: Consecutive line comments are one paragraph, so a sentence wrapped over lines is checked
+// as a whole. An empty line comment or a line without words, like `// ------`, ends the
+// paragraph. A word hyphenated at the end of a line is joined with the rest on the next line.
+// A marker like `TODO:` starts a new paragraph and is not part of the prose.
+pub fn generate(lines: &[LineComment]) -> Vec<Prose> {
+    let mut proses = Vec::new();
+    let mut prose = Prose::new();
+    let mut is_hyphenated = false;
+
+    for (index, line) in lines.iter().enumerate() {
+        if !line.content.chars().any(|c| c.is_alphanumeric()) {
+            push_prose(&mut proses, &mut prose);
+            is_hyphenated = false;
+            continue;
+        }
+
+        let marker_end = annotation_marker::get_marker_end(&line.content);
+        if marker_end > 0 {
+            push_prose(&mut proses, &mut prose);
+            is_hyphenated = false;
+        }
+
+        if !is_hyphenated {
+            prose.push_separator();
+        }
+
+        let next_line = lines.get(index + 1);
+        is_hyphenated = next_line.is_some_and(|next_line| is_hyphenated_word(line, next_line));
+
+        let content = match is_hyphenated {
+            true => &line.content[marker_end..line.content.len() - 1],
+            false => &line.content[marker_end..],
+        };
+
+        prose.push_str(content, line.line_number, line.column + marker_end);
+    }
+
+    push_prose(&mut proses, &mut prose);
+
+    return proses;
+}
+
+// INFO: A word like `exam-` at the end of the line, followed by a lowercase word like `ple`.
+fn is_hyphenated_word(line: &LineComment, next_line: &LineComment) -> bool {
+    let word = match line.content.strip_suffix('-') {
+        Some(word) => word,
+        None => return false,
+    };
+
+    return word.chars().last().is_some_and(|c| c.is_alphabetic())
+        && next_line.line_number == line.line_number + 1
+        && next_line
+            .content
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_lowercase());
+}
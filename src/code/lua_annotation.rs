@@ -0,0 +1,123 @@
+// INFO: Start of the description of a LuaLS annotation, like `@param bufnr integer The buffer`.
+// Tags, names and type expressions are not prose, unknown tags have no description.
+// Returns None when the content is not an annotation.
+pub fn get_description_start(content: &str) -> Option<usize> {
+    let annotation = content.trim_start();
+
+    if !annotation.starts_with('@') {
+        return None;
+    }
+
+    let tag_start = content.len() - annotation.len();
+    let tag_end = skip_word(content, tag_start);
+
+    let description_start = match &content[tag_start + 1..tag_end] {
+        "param" => skip_type(content, skip_word(content, tag_end)),
+        "type" => skip_type(content, tag_end),
+        "return" => {
+            let type_end = skip_type(content, tag_end);
+
+            // INFO: `@return type # description` has no name.
+            match content[type_end..].trim_start().starts_with('#') {
+                true => type_end,
+                false => skip_word(content, type_end),
+            }
+        }
+        "field" => {
+            let mut name_end = skip_word(content, tag_end);
+
+            if ["public", "protected", "private", "package"]
+                .contains(&content[tag_end..name_end].trim())
+            {
+                name_end = skip_word(content, name_end);
+            }
+
+            skip_type(content, name_end)
+        }
+        "class" => skip_class(content, tag_end),
+        _ => content.len(),
+    };
+
+    // INFO: The description can be separated by `#`, or `@` in EmmyLua.
+    let description = content[description_start..].trim_start();
+    let description = description
+        .strip_prefix('#')
+        .or_else(|| description.strip_prefix('@'))
+        .unwrap_or(description)
+        .trim_start();
+
+    return Some(content.len() - description.len());
+}
+
+fn skip_whitespace(content: &str, start: usize) -> usize {
+    return content.len() - content[start..].trim_start().len();
+}
+
+fn skip_word(content: &str, start: usize) -> usize {
+    let word_start = skip_whitespace(content, start);
+
+    return match content[word_start..].find(char::is_whitespace) {
+        Some(word_len) => word_start + word_len,
+        None => content.len(),
+    };
+}
+
+// INFO: Type expressions can have whitespace inside of brackets and around unions, like
+// `table<string, integer>`, `string | nil` or `fun(value: any): boolean`.
+fn skip_type(content: &str, start: usize) -> usize {
+    let bytes = content.as_bytes();
+    let mut index = skip_whitespace(content, start);
+    let mut depth: isize = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'(' | b'<' | b'{' | b'[' => depth += 1,
+            b')' | b'>' | b'}' | b']' => depth -= 1,
+            b'|' if depth == 0 => {
+                index = skip_whitespace(content, index + 1);
+                continue;
+            }
+            b':' if depth == 0 && 0 < index && bytes[index - 1] == b')' => {
+                index = skip_whitespace(content, index + 1);
+                continue;
+            }
+            byte if byte.is_ascii_whitespace() && depth <= 0 => {
+                let next = skip_whitespace(content, index);
+
+                if next < bytes.len() && bytes[next] == b'|' {
+                    index = next;
+                    continue;
+                }
+
+                break;
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    return index;
+}
+
+// INFO: Class name with an optional attribute and parents, like `(exact) Name: Parent, Other`.
+fn skip_class(content: &str, start: usize) -> usize {
+    let mut index = skip_word(content, start);
+
+    if content[start..index].trim_start().starts_with('(') {
+        index = skip_word(content, index);
+    }
+
+    while index < content.len() {
+        let is_parent = content[..index].ends_with([':', ','])
+            || content[index..].trim_start().starts_with([':', ',']);
+
+        if !is_parent {
+            break;
+        }
+
+        index = skip_word(content, index);
+    }
+
+    return index;
+}
@@ -0,0 +1,509 @@
+use super::prose::Prose;
+
+pub const MARKDOWN_EXTENSIONS: [&str; 2] = [".md", ".markdown"];
+
+pub fn is_markdown_file(file_path: &str) -> bool {
+    return MARKDOWN_EXTENSIONS
+        .iter()
+        .any(|extension| file_path.ends_with(extension));
+}
+
+// INFO: Every paragraph, heading, list item and table row of the document is its own prose.
+// Front matter, fenced code, indented code and html comments are skipped.
+pub fn generate(lines: &[String]) -> Vec<Prose> {
+    let mut proses = Vec::new();
+    let mut prose = Prose::new();
+    let mut front_matter: Option<&str> = None;
+    let mut fence: Option<(u8, usize)> = None;
+    let mut is_html_comment = false;
+    let mut is_list = false;
+    let mut is_previous_blank = true;
+
+    for (index, line) in lines.iter().enumerate() {
+        let line_number = index + 1;
+        let trimmed = line.trim();
+
+        if index == 0 && (trimmed == "---" || trimmed == "+++") {
+            front_matter = Some(trimmed);
+            continue;
+        }
+
+        if let Some(delimiter) = front_matter {
+            if trimmed == delimiter || (delimiter == "---" && trimmed == "...") {
+                front_matter = None;
+            }
+            continue;
+        }
+
+        if let Some((fence_char, fence_len)) = fence {
+            let run = count_char(trimmed, fence_char);
+
+            if fence_len <= run && run == trimmed.len() {
+                fence = None;
+            }
+            continue;
+        }
+
+        if is_html_comment {
+            if let Some(comment_end) = line.find("-->") {
+                is_html_comment = false;
+                let start = comment_end + 3;
+                let end = line.trim_end().len();
+
+                if start < end {
+                    prose.push_separator();
+                    is_html_comment = push_inline(&mut prose, line, line_number, start, end);
+                }
+            }
+            continue;
+        }
+
+        if trimmed.is_empty() {
+            push_prose(&mut proses, &mut prose);
+            is_previous_blank = true;
+            continue;
+        }
+
+        let indent = line.len() - line.trim_start().len();
+
+        // INFO: Indented code block, list items can be continued with indentation.
+        if is_previous_blank && !is_list && (4 <= indent || line.starts_with('\t')) {
+            continue;
+        }
+
+        if is_previous_blank && indent == 0 {
+            is_list = false;
+        }
+
+        is_previous_blank = false;
+
+        let start = get_block_quote_end(line);
+        let end = line.trim_end().len();
+        let content = &line[start..end];
+
+        if let Some(fence_start) = get_fence_start(content) {
+            push_prose(&mut proses, &mut prose);
+            fence = Some(fence_start);
+            continue;
+        }
+
+        if is_thematic_break(content) || is_table_delimiter(content) {
+            push_prose(&mut proses, &mut prose);
+            continue;
+        }
+
+        if is_link_reference_definition(content) {
+            push_prose(&mut proses, &mut prose);
+            continue;
+        }
+
+        if let Some(heading_start) = get_heading_start(content) {
+            push_prose(&mut proses, &mut prose);
+            let heading_end = start + content.trim_end_matches('#').trim_end().len();
+            is_html_comment = push_inline(
+                &mut prose,
+                line,
+                line_number,
+                start + heading_start,
+                heading_end.max(start + heading_start),
+            );
+            push_prose(&mut proses, &mut prose);
+            continue;
+        }
+
+        if let Some(list_start) = get_list_item_start(content) {
+            push_prose(&mut proses, &mut prose);
+            is_list = true;
+            is_html_comment = push_inline(&mut prose, line, line_number, start + list_start, end);
+            continue;
+        }
+
+        if content.starts_with('|') {
+            push_prose(&mut proses, &mut prose);
+            is_html_comment = push_inline(&mut prose, line, line_number, start, end);
+            push_prose(&mut proses, &mut prose);
+            continue;
+        }
+
+        prose.push_separator();
+        is_html_comment = push_inline(&mut prose, line, line_number, start, end);
+    }
+
+    push_prose(&mut proses, &mut prose);
+
+    return proses;
+}
+
+pub fn push_prose(proses: &mut Vec<Prose>, prose: &mut Prose) {
+    let current_prose = std::mem::replace(prose, Prose::new());
+
+    if current_prose.is_empty() {
+        return;
+    }
+
+    proses.push(current_prose.finish());
+}
+
+// INFO: Push the inline text of the line between start and end. Code spans, URLs and autolinks
+// are masked, link destinations, images, html tags and emphasis markers are removed.
+// Returns true when a html comment is not closed on this line.
+pub fn push_inline(
+    prose: &mut Prose,
+    line: &str,
+    line_number: usize,
+    start: usize,
+    end: usize,
+) -> bool {
+    let bytes = line.as_bytes();
+    let mut piece_start = start;
+    let mut index = start;
+
+    while index < end {
+        let byte = bytes[index];
+
+        if byte == b'\\' && index + 1 < end && bytes[index + 1].is_ascii_punctuation() {
+            prose.push_str(&line[piece_start..index], line_number, piece_start);
+            piece_start = index + 1;
+            index += 2;
+            continue;
+        }
+
+        if byte == b'`' {
+            let run = count_char(&line[index..end], b'`');
+
+            match find_code_span_end(line, index + run, end, run) {
+                Some(code_span_end) => {
+                    prose.push_str(&line[piece_start..index], line_number, piece_start);
+                    prose.push_mask(line[index..code_span_end].chars().count());
+                    index = code_span_end;
+                    piece_start = index;
+                }
+                None => index += run,
+            }
+            continue;
+        }
+
+        if byte == b'!' && index + 1 < end && bytes[index + 1] == b'[' {
+            if let Some((_, link_end)) = find_link(line, index + 1, end) {
+                prose.push_str(&line[piece_start..index], line_number, piece_start);
+                index = link_end;
+                piece_start = index;
+                continue;
+            }
+        }
+
+        if byte == b'[' {
+            if let Some((text_end, link_end)) = find_link(line, index, end) {
+                prose.push_str(&line[piece_start..index], line_number, piece_start);
+                push_inline(prose, line, line_number, index + 1, text_end);
+                index = link_end;
+                piece_start = index;
+                continue;
+            }
+        }
+
+        if byte == b'<' {
+            if line[index..end].starts_with("<!--") {
+                prose.push_str(&line[piece_start..index], line_number, piece_start);
+
+                match line[index + 4..end].find("-->") {
+                    Some(comment_end) => {
+                        index = index + 4 + comment_end + 3;
+                        piece_start = index;
+                        continue;
+                    }
+                    None => return true,
+                }
+            }
+
+            if let Some(tag_end) = find_tag_end(line, index, end) {
+                prose.push_str(&line[piece_start..index], line_number, piece_start);
+                let tag = &line[index + 1..tag_end];
+
+                if tag.contains("://") || tag.contains('@') {
+                    prose.push_mask(tag.chars().count() + 2);
+                } else if 0 < index
+                    && bytes[index - 1].is_ascii_alphanumeric()
+                    && tag_end + 1 < end
+                    && bytes[tag_end + 1].is_ascii_alphanumeric()
+                {
+                    prose.push_separator();
+                }
+
+                index = tag_end + 1;
+                piece_start = index;
+                continue;
+            }
+        }
+
+        if (byte == b'h' || byte == b'w')
+            && (index == start
+                || bytes[index - 1].is_ascii_whitespace()
+                || bytes[index - 1] == b'(')
+            && is_url_start(&line[index..end])
+        {
+            let url_end = find_url_end(line, index, end);
+            prose.push_str(&line[piece_start..index], line_number, piece_start);
+            prose.push_mask(line[index..url_end].chars().count());
+            index = url_end;
+            piece_start = index;
+            continue;
+        }
+
+        if byte == b'*' || byte == b'_' || byte == b'~' {
+            let run = count_char(&line[index..end], byte);
+            let is_previous_word = start < index && bytes[index - 1].is_ascii_alphanumeric();
+            let is_next_word = index + run < end && bytes[index + run].is_ascii_alphanumeric();
+
+            // INFO: Markers inside of a word like snake_case are part of the word.
+            if (byte != b'~' || run == 2) && !(is_previous_word && is_next_word) {
+                prose.push_str(&line[piece_start..index], line_number, piece_start);
+                piece_start = index + run;
+            }
+
+            index += run;
+            continue;
+        }
+
+        if byte == b'&' {
+            if let Some(entity_end) = find_entity_end(line, index, end) {
+                prose.push_str(&line[piece_start..index], line_number, piece_start);
+                prose.push_separator();
+                index = entity_end;
+                piece_start = index;
+                continue;
+            }
+        }
+
+        if byte == b'|' {
+            prose.push_str(&line[piece_start..index], line_number, piece_start);
+            prose.push_separator();
+            index += 1;
+            piece_start = index;
+            continue;
+        }
+
+        index += 1;
+    }
+
+    prose.push_str(&line[piece_start..end], line_number, piece_start);
+
+    return false;
+}
+
+fn count_char(value: &str, c: u8) -> usize {
+    return value.bytes().take_while(|b| *b == c).count();
+}
+
+fn get_block_quote_end(line: &str) -> usize {
+    let bytes = line.as_bytes();
+    let mut index = line.len() - line.trim_start().len();
+
+    while index < bytes.len() && bytes[index] == b'>' {
+        index += 1;
+
+        while index < bytes.len() && bytes[index] == b' ' {
+            index += 1;
+        }
+    }
+
+    return index;
+}
+
+fn get_fence_start(content: &str) -> Option<(u8, usize)> {
+    for fence_char in [b'`', b'~'] {
+        let run = count_char(content, fence_char);
+
+        if 3 <= run {
+            return Some((fence_char, run));
+        }
+    }
+
+    return None;
+}
+
+fn is_thematic_break(content: &str) -> bool {
+    for break_char in ['-', '*', '_', '='] {
+        let count = content.chars().filter(|c| *c == break_char).count();
+
+        if 3 <= count && content.chars().all(|c| c == break_char || c == ' ') {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+fn is_table_delimiter(content: &str) -> bool {
+    return content.contains('|')
+        && content.contains('-')
+        && content
+            .chars()
+            .all(|c| c == '|' || c == '-' || c == ':' || c == ' ');
+}
+
+fn is_link_reference_definition(content: &str) -> bool {
+    if !content.starts_with('[') {
+        return false;
+    }
+
+    return match content.find("]:") {
+        Some(index) => !content[..index].contains(']'),
+        None => false,
+    };
+}
+
+fn get_heading_start(content: &str) -> Option<usize> {
+    let level = count_char(content, b'#');
+
+    if level == 0 || 6 < level {
+        return None;
+    }
+
+    if content.len() == level {
+        return Some(level);
+    }
+
+    if content.as_bytes()[level] != b' ' {
+        return None;
+    }
+
+    return Some(level + 1);
+}
+
+fn get_list_item_start(content: &str) -> Option<usize> {
+    let bytes = content.as_bytes();
+    let digits = content.bytes().take_while(|b| b.is_ascii_digit()).count();
+
+    let marker_end = if matches!(bytes.first(), Some(b'-' | b'*' | b'+')) {
+        1
+    } else if 0 < digits && digits < 10 && matches!(bytes.get(digits), Some(b'.' | b')')) {
+        digits + 1
+    } else {
+        return None;
+    };
+
+    if bytes.len() != marker_end && bytes[marker_end] != b' ' {
+        return None;
+    }
+
+    let mut list_start = (marker_end + 1).min(bytes.len());
+
+    // INFO: Task list item, like `- [x] Done`.
+    for task in ["[ ] ", "[x] ", "[X] "] {
+        if content[list_start..].starts_with(task) {
+            list_start += task.len();
+        }
+    }
+
+    return Some(list_start);
+}
+
+fn find_code_span_end(line: &str, start: usize, end: usize, run: usize) -> Option<usize> {
+    let mut index = start;
+
+    while index < end {
+        let current_run = count_char(&line[index..end], b'`');
+
+        if current_run == run {
+            return Some(index + run);
+        }
+
+        index += current_run.max(1);
+    }
+
+    return None;
+}
+
+// INFO: Find the end of the link text and the end of the whole link, `[text](url)` or
+// `[text][reference]`.
+fn find_link(line: &str, start: usize, end: usize) -> Option<(usize, usize)> {
+    let text_end = find_closing(line, start, end, b'[', b']')?;
+
+    if end <= text_end + 1 {
+        return None;
+    }
+
+    return match line.as_bytes()[text_end + 1] {
+        b'(' => Some((
+            text_end,
+            find_closing(line, text_end + 1, end, b'(', b')')? + 1,
+        )),
+        b'[' => Some((
+            text_end,
+            find_closing(line, text_end + 1, end, b'[', b']')? + 1,
+        )),
+        _ => None,
+    };
+}
+
+fn find_closing(line: &str, start: usize, end: usize, open: u8, close: u8) -> Option<usize> {
+    let mut level = 0;
+
+    for (index, byte) in line.as_bytes()[start..end].iter().enumerate() {
+        if *byte == open {
+            level += 1;
+        } else if *byte == close {
+            level -= 1;
+
+            if level == 0 {
+                return Some(start + index);
+            }
+        }
+    }
+
+    return None;
+}
+
+fn find_tag_end(line: &str, start: usize, end: usize) -> Option<usize> {
+    let bytes = line.as_bytes();
+
+    if end <= start + 1 || !(bytes[start + 1].is_ascii_alphabetic() || bytes[start + 1] == b'/') {
+        return None;
+    }
+
+    let tag_len = line[start + 1..end].find(['>', '<'])?;
+    let tag_end = start + 1 + tag_len;
+
+    if bytes[tag_end] != b'>' {
+        return None;
+    }
+
+    return Some(tag_end);
+}
+
+fn is_url_start(value: &str) -> bool {
+    return value.starts_with("http://")
+        || value.starts_with("https://")
+        || value.starts_with("www.");
+}
+
+fn find_url_end(line: &str, start: usize, end: usize) -> usize {
+    let url_end = match line[start..end].find(|c: char| c.is_whitespace() || c == '<') {
+        Some(index) => start + index,
+        None => end,
+    };
+
+    return start
+        + line[start..url_end]
+            .trim_end_matches(['.', ',', ';', ':', '!', '?', ')', '\'', '"'])
+            .len();
+}
+
+fn find_entity_end(line: &str, start: usize, end: usize) -> Option<usize> {
+    let entity_len = line[start + 1..end].find(';')?;
+    let entity = &line[start + 1..start + 1 + entity_len];
+
+    if entity.is_empty() || 10 < entity.len() {
+        return None;
+    }
+
+    if !entity
+        .bytes()
+        .all(|b| b.is_ascii_alphanumeric() || b == b'#')
+    {
+        return None;
+    }
+
+    return Some(start + entity_len + 2);
+}
@@ -1,2 +1,16 @@
+pub mod annotation_marker;
 pub mod code_file;
+pub mod commented_code;
+pub mod config;
+pub mod definition;
+pub mod doc_comment;
+pub mod filetype;
+pub mod git_message;
+pub mod line_comment;
+pub mod lua_annotation;
+pub mod markdown;
 pub mod programming;
+pub mod prose;
+pub mod rust_literal;
+pub mod string_context;
+pub mod string_literal;
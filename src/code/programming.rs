@@ -4,10 +4,30 @@ use log::info;
 
 use crate::{common::string::DelimiterType, nvim_lang_dictionary::NvimLanguageReadonlyDictionary};
 
+use super::{
+    commented_code,
+    doc_comment::DocCommentLine,
+    line_comment::LineComment,
+    lua_annotation,
+    prose::Prose,
+    rust_literal::{self, OpenAttribute},
+};
+
 #[derive(Debug)]
 pub enum ProgrammingLanguageType {
+    // INFO: Languages loaded at runtime from a language definition file.
+    Custom,
+    // INFO: Languages built from the Neovim `commentstring`, only comments are checked.
+    CommentString,
     Lua,
     Rust,
+    Python,
+    JavaScript,
+    TypeScript,
+    Go,
+    C,
+    Cpp,
+    Shell,
 }
 
 #[derive(Debug)]
@@ -17,76 +37,144 @@ pub enum NamingConvetionType {
     None,
 }
 
+// INFO: Placeholders in format strings, they are replaced by a word before a string is checked.
+#[derive(Debug)]
+pub enum FormatPlaceholderSyntax {
+    // INFO: `{}`, `{name:?}` or `{0:>5}`, `{{` is an escaped `{`.
+    Brace,
+    // INFO: `%s`, `%5.2f`, `%-10d` or Python `%(name)s`, `%%` is an escaped `%`.
+    Printf,
+}
+
+// INFO: Takes the line number, the line and the attribute that is open from the previous line.
+pub type CodeProseGenerator = fn(usize, String, &mut Option<OpenAttribute>) -> (Vec<Prose>, String);
+
 #[derive(Debug)]
-pub struct ProgrammingLanguage<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize> {
+pub struct ProgrammingLanguage {
     pub extension: &'static str,
+    // INFO: The Neovim `filetype` of the language.
+    pub filetype: &'static str,
     pub comment_delimiter: &'static str,
     pub block_comment: CodeBlockSyntax,
-    pub operators_and_syntax: [&'static str; OPERATOR_COUNT],
-    pub reserved_keywords: [&'static str; RESERVED_KEYWORD_COUNT],
+    // INFO: Decoration at the start of every block comment line, like the JSDoc ` * `.
+    pub block_comment_line_prefix: Option<&'static str>,
+    // INFO: Line comments that are Markdown documentation, like Rust `///` and `//!`.
+    pub doc_comment_delimiters: &'static [&'static str],
+    // INFO: Start of the description in a doc comment annotation, like LuaLS `---@param`.
+    pub doc_comment_annotation: Option<fn(&str) -> Option<usize>>,
+    // INFO: Lines starting with this are handled as code only, like C `#include "file.h"`.
+    pub preprocessor_directive: Option<&'static str>,
+    pub operators_and_syntax: &'static [&'static str],
+    pub reserved_keywords: &'static [&'static str],
     pub string_syntax: [ProgrammingStringSyntax; 2],
+    // INFO: Characters that may prefix a string literal, like Python `f"..."` or `rb"..."`.
+    pub string_prefixes: &'static str,
+    pub string_interpolation: Option<StringInterpolationSyntax>,
+    pub format_placeholders: &'static [FormatPlaceholderSyntax],
     pub block_string: CodeBlockSyntax,
     pub naming_conventions: [NamingConvetionType; 2],
     pub lang_type: ProgrammingLanguageType,
-    post_replace: Option<[&'static str; 2]>,
+    // INFO: Mask code that looks like strings or words, like Rust lifetimes and attributes.
+    pub code_literal_mask: Option<fn(String) -> String>,
+    // INFO: Prose inside of code, like Rust `#[doc = "..."]` attributes, and the line with the
+    // part of an attribute that continues from the previous line masked.
+    pub code_prose: Option<CodeProseGenerator>,
 }
 
-pub const LUA: ProgrammingLanguage<27, 21> = ProgrammingLanguage {
+pub const LUA: ProgrammingLanguage = ProgrammingLanguage {
     extension: ".lua",
+    filetype: "lua",
     comment_delimiter: "--",
     block_comment: CodeBlockSyntax {
         start_delmiters: [
-            DelimiterType::DelimiterStr("--[["),
-            DelimiterType::DelimiterStr("--[=["),
-            DelimiterType::DelimiterStr("--[==["),
-            DelimiterType::DelimiterStr("--[===["),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
         ],
         end_delmiters: [
-            DelimiterType::DelimiterStr("]]"),
-            DelimiterType::DelimiterStr("]=]"),
-            DelimiterType::DelimiterStr("]==]"),
-            DelimiterType::DelimiterStr("]===]"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
         ],
+        // INFO: Long brackets of any level, like `--[==[ ... ]==]`.
+        leveled_delimiter: Some(LeveledDelimiterSyntax {
+            start_open: "--[",
+            start_close: '[',
+            end_open: "]",
+            end_close: "]",
+            level_char: Some('='),
+            min_level: 0,
+            max_level: usize::MAX,
+        }),
+        heredoc: None,
+        is_nested: false,
     },
+    block_comment_line_prefix: None,
+    doc_comment_delimiters: &["---"],
+    doc_comment_annotation: Some(lua_annotation::get_description_start),
+    preprocessor_directive: None,
     block_string: CodeBlockSyntax {
         start_delmiters: [
-            DelimiterType::DelimiterStr("[["),
-            DelimiterType::DelimiterStr("[=["),
-            DelimiterType::DelimiterStr("[==["),
-            DelimiterType::DelimiterStr("[===["),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
         ],
         end_delmiters: [
-            DelimiterType::DelimiterStr("]]"),
-            DelimiterType::DelimiterStr("]=]"),
-            DelimiterType::DelimiterStr("]==]"),
-            DelimiterType::DelimiterStr("]===]"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
         ],
+        // INFO: Long brackets of any level, like `[==[ ... ]==]`.
+        leveled_delimiter: Some(LeveledDelimiterSyntax {
+            start_open: "[",
+            start_close: '[',
+            end_open: "]",
+            end_close: "]",
+            level_char: Some('='),
+            min_level: 0,
+            max_level: usize::MAX,
+        }),
+        heredoc: None,
+        is_nested: false,
     },
     string_syntax: [
         ProgrammingStringSyntax {
             string_delimiter: DelimiterType::DelimiterChar('"'),
             string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &["\\z", "\\"],
         },
         ProgrammingStringSyntax {
             string_delimiter: DelimiterType::DelimiterChar('\''),
             string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\'"), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &["\\z", "\\"],
         },
     ],
-    reserved_keywords: [
+    reserved_keywords: &[
         "and", "break", "do", "else", "elseif", "end", "false", "for", "function", "if", "in",
         "local", "nil", "not", "or", "repeat", "return", "then", "true", "until", "while",
     ],
-    operators_and_syntax: [
+    operators_and_syntax: &[
         "_", "+", "-", "*", "/", "%", "=", "'", "\"", "~", ">", "<", "^", "/=", "%=", "(", ")",
         "[", "]", "{", "}", ";", ":", ",", "..", ".", "#",
     ],
+    string_prefixes: "",
+    string_interpolation: None,
+    format_placeholders: &[FormatPlaceholderSyntax::Printf],
     naming_conventions: [NamingConvetionType::None, NamingConvetionType::None],
     lang_type: ProgrammingLanguageType::Lua,
-    post_replace: None,
+    code_literal_mask: None,
+    code_prose: None,
 };
 
-pub const RUST: ProgrammingLanguage<29, 50> = ProgrammingLanguage {
+pub const RUST: ProgrammingLanguage = ProgrammingLanguage {
     extension: ".rs",
+    filetype: "rust",
     comment_delimiter: "//",
     block_comment: CodeBlockSyntax {
         start_delmiters: [
@@ -101,121 +189,894 @@ pub const RUST: ProgrammingLanguage<29, 50> = ProgrammingLanguage {
             DelimiterType::None,
             DelimiterType::None,
         ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: true,
     },
+    block_comment_line_prefix: None,
+    doc_comment_delimiters: &["///", "//!"],
+    doc_comment_annotation: None,
+    preprocessor_directive: None,
     block_string: CodeBlockSyntax {
         start_delmiters: [
-            DelimiterType::DelimiterStr("r#\""),
-            DelimiterType::DelimiterStr("r##\""),
-            DelimiterType::DelimiterStr("r###\""),
-            DelimiterType::DelimiterStr("r####\""),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
         ],
         end_delmiters: [
-            DelimiterType::DelimiterStr("\"#"),
-            DelimiterType::DelimiterStr("\"##"),
-            DelimiterType::DelimiterStr("\"###"),
-            DelimiterType::DelimiterStr("\"####"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
         ],
+        // INFO: Raw strings with any count of hashes, like `r##"..."##`.
+        leveled_delimiter: Some(LeveledDelimiterSyntax {
+            start_open: "r",
+            start_close: '"',
+            end_open: "\"",
+            end_close: "",
+            level_char: Some('#'),
+            min_level: 1,
+            max_level: usize::MAX,
+        }),
+        heredoc: None,
+        is_nested: false,
     },
     string_syntax: [
         ProgrammingStringSyntax {
             string_delimiter: DelimiterType::DelimiterChar('"'),
             string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: true,
+            line_continuations: &["\\"],
         },
+        // INFO: Char literals and lifetimes are masked as code, so `'` is not a string.
         ProgrammingStringSyntax {
-            string_delimiter: DelimiterType::DelimiterChar('\''),
-            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\'"), DelimiterType::None],
+            string_delimiter: DelimiterType::None,
+            string_ignore_delimiter: [DelimiterType::None, DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
         },
     ],
-    reserved_keywords: [
+    reserved_keywords: &[
         "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum",
         "extern", "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move",
         "mut", "pub", "ref", "return", "Self", "self", "static", "struct", "super", "trait",
         "true", "type", "unsafe", "use", "where", "while", "str", "usize", "isize", "bool", "i8",
         "i16", "i32", "i64", "u8", "u16", "u32", "u64",
     ],
-    operators_and_syntax: [
+    operators_and_syntax: &[
         "_", "+", "-", "*", "/", "%", "=", "\"", "!", ">", "<", "&", "|", "'", "^", "/=", "%=",
         "(", ")", "{", "}", "[", "]", ";", ":", ",", "..", ".", "#",
     ],
+    string_prefixes: "",
+    string_interpolation: None,
+    format_placeholders: &[FormatPlaceholderSyntax::Brace],
     naming_conventions: [NamingConvetionType::PascalCase, NamingConvetionType::None],
     lang_type: ProgrammingLanguageType::Rust,
-    post_replace: Some(["&'", "<'"]),
+    code_literal_mask: Some(rust_literal::mask_code_literals),
+    code_prose: Some(rust_literal::generate_attribute_proses),
 };
 
-impl<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize>
-    ProgrammingLanguage<OPERATOR_COUNT, RESERVED_KEYWORD_COUNT>
-{
-    pub fn is_reserved_keyword(&self, input: &str) -> bool {
-        for reserved_keyword in &self.reserved_keywords {
-            if input == *reserved_keyword {
-                return true;
-            }
-        }
-
-        return false;
-    }
-
-    pub fn post_replace_with_empty_space(&self, mut line: String) -> String {
-        return match self.post_replace {
-            Some(r) => {
-                for c in r {
-                    line = line.replace(c, "");
-                }
-
-                return line;
-            }
-            None => line,
-        };
-    }
-
-    pub fn replase_all_operators_and_syntax_with_whitespace(&self, mut input: String) -> String {
-        for op_snt in &self.operators_and_syntax {
-            input = input.replace(op_snt, " ");
-        }
-
-        return input;
-    }
+pub const PYTHON: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".py",
+    filetype: "python",
+    comment_delimiter: "#",
+    block_comment: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    block_comment_line_prefix: None,
+    doc_comment_delimiters: &[],
+    doc_comment_annotation: None,
+    preprocessor_directive: None,
+    // INFO: Docstrings are triple-quoted strings, so they are handled as block strings.
+    block_string: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::DelimiterStr("\"\"\""),
+            DelimiterType::DelimiterStr("'''"),
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::DelimiterStr("\"\"\""),
+            DelimiterType::DelimiterStr("'''"),
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    string_syntax: [
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('"'),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('\''),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\'"), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+    ],
+    string_prefixes: "rRbBuUfF",
+    string_interpolation: Some(StringInterpolationSyntax {
+        string_prefixes: "fF",
+        string_delimiter: DelimiterType::None,
+        start_delimiter: DelimiterType::DelimiterChar('{'),
+        end_delimiter: DelimiterType::DelimiterChar('}'),
+        variable_prefix: None,
+    }),
+    reserved_keywords: &[
+        "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class",
+        "continue", "def", "del", "elif", "else", "except", "finally", "for", "from", "global",
+        "if", "import", "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return",
+        "try", "while", "with", "yield", "self", "cls", "str", "int", "bool",
+    ],
+    operators_and_syntax: &[
+        "_", "+", "-", "*", "/", "%", "=", "\"", "'", "!", ">", "<", "&", "|", "~", "^", "@", "(",
+        ")", "[", "]", "{", "}", ";", ":", ",", ".", "\\",
+    ],
+    format_placeholders: &[
+        FormatPlaceholderSyntax::Brace,
+        FormatPlaceholderSyntax::Printf,
+    ],
+    naming_conventions: [NamingConvetionType::PascalCase, NamingConvetionType::None],
+    lang_type: ProgrammingLanguageType::Python,
+    code_literal_mask: None,
+    code_prose: None,
+};
 
-    // FIX: Name of function is doing a lot more then what the name is saying
-    pub fn replase_all_reserved_keywords_with_whitespace(
-        &self,
-        mut input: String,
-        nvim_language_readonly_dictionary: &NvimLanguageReadonlyDictionary,
-    ) -> String {
-        let mut transform = String::new();
-        let split_whitespace = input.split_whitespace();
+pub const JAVASCRIPT: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".js",
+    filetype: "javascript",
+    comment_delimiter: "//",
+    block_comment: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::DelimiterStr("/*"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::DelimiterStr("*/"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    block_comment_line_prefix: Some("*"),
+    doc_comment_delimiters: &[],
+    doc_comment_annotation: None,
+    preprocessor_directive: None,
+    // INFO: Template literals can span multiple lines, so they are handled as block strings.
+    block_string: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::DelimiterChar('`'),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::DelimiterChar('`'),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    string_syntax: [
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('"'),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('\''),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\'"), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+    ],
+    string_prefixes: "",
+    string_interpolation: Some(StringInterpolationSyntax {
+        string_prefixes: "",
+        string_delimiter: DelimiterType::DelimiterChar('`'),
+        start_delimiter: DelimiterType::DelimiterStr("${"),
+        end_delimiter: DelimiterType::DelimiterChar('}'),
+        variable_prefix: None,
+    }),
+    reserved_keywords: &[
+        "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete",
+        "do", "else", "export", "extends", "false", "finally", "for", "function", "if", "import",
+        "in", "instanceof", "new", "null", "return", "super", "switch", "this", "throw", "true",
+        "try", "typeof", "var", "void", "while", "with", "yield", "let", "static", "async", "await",
+        "of", "undefined",
+    ],
+    operators_and_syntax: &[
+        "_", "+", "-", "*", "/", "%", "=", "\"", "'", "`", "!", ">", "<", "&", "|", "^", "~", "?",
+        "(", ")", "{", "}", "[", "]", ";", ":", ",", ".", "$", "@", "#",
+    ],
+    format_placeholders: &[FormatPlaceholderSyntax::Printf],
+    naming_conventions: [
+        NamingConvetionType::CamelCase,
+        NamingConvetionType::PascalCase,
+    ],
+    lang_type: ProgrammingLanguageType::JavaScript,
+    code_literal_mask: None,
+    code_prose: None,
+};
 
-        'ignore_chunk: for chunk in split_whitespace {
-            let chunk = chunk.trim();
-            // HACK: This will ignore all one/two char words
-            if chunk.len() <= 2 {
-                continue;
-            }
+pub const JSX: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".jsx",
+    filetype: "javascriptreact",
+    ..JAVASCRIPT
+};
 
-            for keyword in self.reserved_keywords {
-                if keyword == chunk {
-                    continue 'ignore_chunk;
-                }
-            }
+pub const TYPESCRIPT: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".ts",
+    filetype: "typescript",
+    reserved_keywords: &[
+        "break", "case", "catch", "class", "const", "continue", "debugger", "default", "delete",
+        "do", "else", "export", "extends", "false", "finally", "for", "function", "if", "import",
+        "in", "instanceof", "new", "null", "return", "super", "switch", "this", "throw", "true",
+        "try", "typeof", "var", "void", "while", "with", "yield", "let", "static", "async", "await",
+        "of", "undefined", "interface", "type", "enum", "implements", "private", "protected",
+        "public", "readonly", "namespace", "declare", "abstract", "as", "any", "boolean", "number",
+        "string", "unknown", "never", "keyof",
+    ],
+    lang_type: ProgrammingLanguageType::TypeScript,
+    ..JAVASCRIPT
+};
 
-            if nvim_language_readonly_dictionary.exit_in_dictionary(chunk) {
-                continue;
-            }
+pub const TSX: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".tsx",
+    filetype: "typescriptreact",
+    ..TYPESCRIPT
+};
 
-            let chunk = self.split_by_uppercase(chunk);
-            let chunk = chunk.trim();
-            transform.push_str(chunk);
-            transform.push(' ');
-        }
+pub const GO: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".go",
+    filetype: "go",
+    comment_delimiter: "//",
+    block_comment: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::DelimiterStr("/*"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::DelimiterStr("*/"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    block_comment_line_prefix: None,
+    doc_comment_delimiters: &[],
+    doc_comment_annotation: None,
+    preprocessor_directive: None,
+    // INFO: Raw strings use backticks and can span multiple lines.
+    block_string: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::DelimiterChar('`'),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::DelimiterChar('`'),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    string_syntax: [
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('"'),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('\''),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\'"), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+    ],
+    string_prefixes: "",
+    string_interpolation: None,
+    reserved_keywords: &[
+        "break",
+        "case",
+        "chan",
+        "const",
+        "continue",
+        "default",
+        "defer",
+        "else",
+        "fallthrough",
+        "for",
+        "func",
+        "go",
+        "goto",
+        "if",
+        "import",
+        "interface",
+        "map",
+        "package",
+        "range",
+        "return",
+        "select",
+        "struct",
+        "switch",
+        "type",
+        "var",
+        "nil",
+        "true",
+        "false",
+        "string",
+        "int",
+        "int8",
+        "int16",
+        "int32",
+        "int64",
+        "uint",
+        "uint8",
+        "uint16",
+        "uint32",
+        "uint64",
+        "bool",
+        "byte",
+        "rune",
+        "error",
+        "float32",
+        "float64",
+        "any",
+        "make",
+        "len",
+        "append",
+    ],
+    operators_and_syntax: &[
+        "_", "+", "-", "*", "/", "%", "=", "\"", "'", "`", "!", ">", "<", "&", "|", "^", "~", "(",
+        ")", "{", "}", "[", "]", ";", ":", ",", ".",
+    ],
+    format_placeholders: &[FormatPlaceholderSyntax::Printf],
+    naming_conventions: [
+        NamingConvetionType::CamelCase,
+        NamingConvetionType::PascalCase,
+    ],
+    lang_type: ProgrammingLanguageType::Go,
+    code_literal_mask: None,
+    code_prose: None,
+};
+pub const C: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".c",
+    filetype: "c",
+    comment_delimiter: "//",
+    block_comment: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::DelimiterStr("/*"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::DelimiterStr("*/"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    block_comment_line_prefix: Some("*"),
+    doc_comment_delimiters: &[],
+    doc_comment_annotation: None,
+    preprocessor_directive: Some("#"),
+    // INFO: C++ raw strings, like `R"tag(...)tag"`.
+    block_string: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: Some(LeveledDelimiterSyntax {
+            start_open: "R\"",
+            start_close: '(',
+            end_open: ")",
+            end_close: "\"",
+            level_char: None,
+            min_level: 0,
+            max_level: 16,
+        }),
+        heredoc: None,
+        is_nested: false,
+    },
+    string_syntax: [
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('"'),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('\''),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\'"), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+    ],
+    string_prefixes: "",
+    string_interpolation: None,
+    reserved_keywords: &[
+        "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else",
+        "enum", "extern", "float", "for", "goto", "if", "inline", "int", "long", "register",
+        "restrict", "return", "short", "signed", "sizeof", "static", "struct", "switch", "typedef",
+        "union", "unsigned", "void", "volatile", "while", "include", "define", "undef", "ifdef",
+        "ifndef", "endif", "elif", "pragma", "NULL", "true", "false", "bool", "size_t",
+    ],
+    operators_and_syntax: &[
+        "_", "+", "-", "*", "/", "%", "=", "\"", "'", "!", ">", "<", "&", "|", "^", "~", "?", "(",
+        ")", "{", "}", "[", "]", ";", ":", ",", ".", "#", "\\",
+    ],
+    format_placeholders: &[FormatPlaceholderSyntax::Printf],
+    naming_conventions: [NamingConvetionType::None, NamingConvetionType::None],
+    lang_type: ProgrammingLanguageType::C,
+    code_literal_mask: None,
+    code_prose: None,
+};
 
-        return transform;
-    }
+pub const C_HEADER: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".h",
+    ..C
+};
 
-    // WARN: This might not work on utf16 strings!
-    fn split_by_uppercase<'i>(&self, word: &'i str) -> String {
-        const UPPERCASE_UTF8: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
-        let mut output = String::new();
-        let input_bytes = word.as_bytes();
+pub const CPP: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".cpp",
+    filetype: "cpp",
+    comment_delimiter: "//",
+    block_comment: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::DelimiterStr("/*"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::DelimiterStr("*/"),
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    block_comment_line_prefix: Some("*"),
+    doc_comment_delimiters: &[],
+    doc_comment_annotation: None,
+    preprocessor_directive: Some("#"),
+    // INFO: C++ raw strings, like `R"tag(...)tag"`.
+    block_string: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: Some(LeveledDelimiterSyntax {
+            start_open: "R\"",
+            start_close: '(',
+            end_open: ")",
+            end_close: "\"",
+            level_char: None,
+            min_level: 0,
+            max_level: 16,
+        }),
+        heredoc: None,
+        is_nested: false,
+    },
+    string_syntax: [
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('"'),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('\''),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\'"), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+    ],
+    string_prefixes: "",
+    string_interpolation: None,
+    reserved_keywords: &[
+        "auto",
+        "break",
+        "case",
+        "char",
+        "const",
+        "continue",
+        "default",
+        "do",
+        "double",
+        "else",
+        "enum",
+        "extern",
+        "float",
+        "for",
+        "goto",
+        "if",
+        "inline",
+        "int",
+        "long",
+        "register",
+        "restrict",
+        "return",
+        "short",
+        "signed",
+        "sizeof",
+        "static",
+        "struct",
+        "switch",
+        "typedef",
+        "union",
+        "unsigned",
+        "void",
+        "volatile",
+        "while",
+        "include",
+        "define",
+        "undef",
+        "ifdef",
+        "ifndef",
+        "endif",
+        "elif",
+        "pragma",
+        "NULL",
+        "true",
+        "false",
+        "bool",
+        "size_t",
+        "class",
+        "namespace",
+        "template",
+        "typename",
+        "public",
+        "private",
+        "protected",
+        "virtual",
+        "override",
+        "new",
+        "delete",
+        "this",
+        "nullptr",
+        "using",
+        "try",
+        "catch",
+        "throw",
+        "operator",
+        "friend",
+        "explicit",
+        "constexpr",
+        "noexcept",
+        "std",
+        "const_cast",
+        "static_cast",
+        "dynamic_cast",
+        "reinterpret_cast",
+        "mutable",
+    ],
+    operators_and_syntax: &[
+        "_", "+", "-", "*", "/", "%", "=", "\"", "'", "!", ">", "<", "&", "|", "^", "~", "?", "(",
+        ")", "{", "}", "[", "]", ";", ":", ",", ".", "#", "\\",
+    ],
+    format_placeholders: &[
+        FormatPlaceholderSyntax::Printf,
+        FormatPlaceholderSyntax::Brace,
+    ],
+    naming_conventions: [
+        NamingConvetionType::CamelCase,
+        NamingConvetionType::PascalCase,
+    ],
+    lang_type: ProgrammingLanguageType::Cpp,
+    code_literal_mask: None,
+    code_prose: None,
+};
+
+pub const CC: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".cc",
+    ..CPP
+};
+
+pub const CPP_HEADER: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".hpp",
+    ..CPP
+};
+
+pub const SHELL: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".sh",
+    filetype: "sh",
+    comment_delimiter: "#",
+    block_comment: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: None,
+        is_nested: false,
+    },
+    block_comment_line_prefix: None,
+    doc_comment_delimiters: &[],
+    doc_comment_annotation: None,
+    preprocessor_directive: None,
+    // INFO: Heredoc bodies are checked as block strings.
+    block_string: CodeBlockSyntax {
+        start_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        end_delmiters: [
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+            DelimiterType::None,
+        ],
+        leveled_delimiter: None,
+        heredoc: Some(HeredocSyntax { start_open: "<<" }),
+        is_nested: false,
+    },
+    string_syntax: [
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('"'),
+            string_ignore_delimiter: [DelimiterType::DelimiterStr("\\\""), DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+        ProgrammingStringSyntax {
+            string_delimiter: DelimiterType::DelimiterChar('\''),
+            string_ignore_delimiter: [DelimiterType::None, DelimiterType::None],
+            is_multi_line: false,
+            line_continuations: &[],
+        },
+    ],
+    string_prefixes: "",
+    // INFO: Only double quoted strings and heredocs expand variables.
+    string_interpolation: Some(StringInterpolationSyntax {
+        string_prefixes: "",
+        string_delimiter: DelimiterType::DelimiterChar('"'),
+        start_delimiter: DelimiterType::DelimiterStr("${"),
+        end_delimiter: DelimiterType::DelimiterChar('}'),
+        variable_prefix: Some('$'),
+    }),
+    reserved_keywords: &[
+        "if", "then", "else", "elif", "fi", "case", "esac", "for", "select", "while", "until",
+        "do", "done", "in", "function", "time", "declare", "local", "export", "readonly", "return",
+        "exit", "echo", "printf", "read", "set", "unset", "shift", "source", "alias", "cd", "test",
+        "true", "false", "eval", "exec", "trap", "wait", "break", "continue", "let", "typeset",
+    ],
+    operators_and_syntax: &[
+        "_", "+", "-", "*", "/", "%", "=", "\"", "'", "!", ">", "<", "&", "|", "~", "^", "@", "(",
+        ")", "[", "]", "{", "}", ";", ":", ",", ".", "\\", "$", "`", "?",
+    ],
+    format_placeholders: &[FormatPlaceholderSyntax::Printf],
+    naming_conventions: [NamingConvetionType::None, NamingConvetionType::None],
+    lang_type: ProgrammingLanguageType::Shell,
+    code_literal_mask: None,
+    code_prose: None,
+};
+
+pub const BASH: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".bash",
+    filetype: "bash",
+    ..SHELL
+};
+
+pub const ZSH: ProgrammingLanguage = ProgrammingLanguage {
+    extension: ".zsh",
+    filetype: "zsh",
+    ..SHELL
+};
+
+pub const PROGRAMMING_LANGUAGES: [&ProgrammingLanguage; 16] = [
+    &RUST,
+    &LUA,
+    &PYTHON,
+    &JAVASCRIPT,
+    &JSX,
+    &TYPESCRIPT,
+    &TSX,
+    &GO,
+    &C,
+    &C_HEADER,
+    &CPP,
+    &CC,
+    &CPP_HEADER,
+    &SHELL,
+    &BASH,
+    &ZSH,
+];
+
+impl ProgrammingLanguage {
+    pub fn is_reserved_keyword(&self, input: &str) -> bool {
+        for reserved_keyword in self.reserved_keywords {
+            if input == *reserved_keyword {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
+    pub fn is_commented_out_code(&self, comment: &str) -> bool {
+        return commented_code::is_code(comment, self.reserved_keywords);
+    }
+
+    // INFO: Byte ranges of the format placeholders in a string value, like `{}` or `%5.2f`.
+    pub fn find_format_placeholders(&self, value: &str) -> Vec<(usize, usize)> {
+        let mut placeholders = Vec::new();
+
+        if self.format_placeholders.is_empty() {
+            return placeholders;
+        }
+
+        let mut index = 0;
+
+        'value: while index < value.len() {
+            if !value.is_char_boundary(index) {
+                index += 1;
+                continue;
+            }
+
+            for format_placeholder in self.format_placeholders {
+                if let Some(len) = format_placeholder.find_len(value, index) {
+                    if !format_placeholder.is_escaped(&value[index..index + len]) {
+                        placeholders.push((index, index + len));
+                    }
+
+                    index += len;
+                    continue 'value;
+                }
+            }
+
+            index += 1;
+        }
+
+        return placeholders;
+    }
+
+    pub fn generate_code_proses(
+        &self,
+        line_number: usize,
+        line: String,
+        open_attribute: &mut Option<OpenAttribute>,
+    ) -> (Vec<Prose>, String) {
+        let (mut proses, line) = match self.code_prose {
+            Some(code_prose) => code_prose(line_number, line, open_attribute),
+            None => (Vec::new(), line),
+        };
+
+        // INFO: Prose inside of code is a string, like `#[error("Unable to read {path}")]`.
+        for prose in &mut proses {
+            prose.placeholders = self.find_format_placeholders(&prose.value);
+        }
+
+        return (proses, line);
+    }
+
+    pub fn mask_code_literals(&self, line: String) -> String {
+        return match self.code_literal_mask {
+            Some(code_literal_mask) => code_literal_mask(line),
+            None => line,
+        };
+    }
+
+    pub fn replase_all_operators_and_syntax_with_whitespace(&self, mut input: String) -> String {
+        for op_snt in self.operators_and_syntax {
+            input = input.replace(op_snt, " ");
+        }
+
+        return input;
+    }
+
+    // FIX: Name of function is doing a lot more then what the name is saying
+    pub fn replase_all_reserved_keywords_with_whitespace(
+        &self,
+        mut input: String,
+        nvim_language_readonly_dictionary: &NvimLanguageReadonlyDictionary,
+    ) -> String {
+        let mut transform = String::new();
+        let split_whitespace = input.split_whitespace();
+
+        'ignore_chunk: for chunk in split_whitespace {
+            let chunk = chunk.trim();
+            // HACK: This will ignore all one/two char words
+            if chunk.len() <= 2 {
+                continue;
+            }
+
+            for keyword in self.reserved_keywords {
+                if *keyword == chunk {
+                    continue 'ignore_chunk;
+                }
+            }
+
+            if nvim_language_readonly_dictionary.exit_in_dictionary(chunk) {
+                continue;
+            }
+
+            let chunk = self.split_by_uppercase(chunk);
+            let chunk = chunk.trim();
+            transform.push_str(chunk);
+            transform.push(' ');
+        }
+
+        return transform;
+    }
+
+    // WARN: This might not work on utf16 strings!
+    fn split_by_uppercase<'i>(&self, word: &'i str) -> String {
+        const UPPERCASE_UTF8: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+        let mut output = String::new();
+        let input_bytes = word.as_bytes();
         let mut current_index: usize = 0;
         let mut start_index: usize = 0;
         let mut is_first_uppercase = true;
@@ -275,42 +1136,481 @@ impl<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize>
         return output;
     }
 
-    pub fn is_start_of_code_block(&self, line: &str) -> CodeBlockType {
-        let comment_code_block_line_syntax = self.block_comment.get_code_block_line_syntax(line);
-        let string_code_block_line_syntax = self.block_string.get_code_block_line_syntax(line);
+    // INFO: The first block that is still open at the end of the line. Comments, blocks and
+    // strings that end in the line are skipped, so any count of them can be before the block.
+    // Normal strings are only a block when they continue on the next line, like Rust `"...`.
+    pub fn is_start_of_code_block(&'static self, line: &str) -> CodeBlockType {
+        let line = self.mask_code_literals(line.to_owned());
+        let mut index = 0;
 
-        // TODO: Need to use this index to set normal comment
-        let comment_indexof = line.find(self.comment_delimiter).unwrap_or(usize::MAX);
+        while index < line.len() {
+            let rest = &line[index..];
+            let comment_indexof = rest.find(self.comment_delimiter).unwrap_or(usize::MAX);
+            let comment_block_line_syntax = self.block_comment.get_code_block_line_syntax(rest);
+            let string_block_line_syntax = self.block_string.get_code_block_line_syntax(rest);
+            let string = self
+                .string_syntax
+                .iter()
+                .filter_map(|string_syntax| {
+                    Some((string_syntax.string_delimiter.indexof(rest)?, string_syntax))
+                })
+                .min_by_key(|(string_indexof, _)| *string_indexof);
+            let string_indexof = string.map_or(usize::MAX, |(string_indexof, _)| string_indexof);
 
-        if comment_code_block_line_syntax.start_indexof
-            < string_code_block_line_syntax.start_indexof
-            && comment_code_block_line_syntax.start_indexof <= comment_indexof
-        {
-            if !matches!(
-                comment_code_block_line_syntax.end_delimiter.indexof(line),
-                None
-            ) {
+            if comment_indexof < comment_block_line_syntax.start_indexof
+                && comment_indexof < string_block_line_syntax.start_indexof
+                && comment_indexof < string_indexof
+            {
                 return CodeBlockType::None;
             }
 
-            return CodeBlockType::Comment(comment_code_block_line_syntax);
+            let (is_comment, mut block_line_syntax) = if comment_block_line_syntax.start_indexof
+                < string_block_line_syntax.start_indexof
+                && comment_block_line_syntax.start_indexof < string_indexof
+            {
+                (true, comment_block_line_syntax)
+            } else if string_block_line_syntax.start_indexof != usize::MAX
+                // INFO: Block strings like Python `"""` start at the same index as a normal string.
+                && string_block_line_syntax.start_indexof <= string_indexof
+            {
+                (false, string_block_line_syntax)
+            } else {
+                let (string_indexof, string_syntax) = match string {
+                    Some(string) => string,
+                    None => return CodeBlockType::None,
+                };
+                let content_start = string_indexof + string_syntax.string_delimiter.len();
+
+                match string_syntax.find_end(&rest[content_start..]) {
+                    Some(string_len) => index += content_start + string_len,
+                    None if string_syntax.is_continued(&line) => {
+                        return CodeBlockType::String(CodeBlockLineSyntax {
+                            start_indexof: index + string_indexof,
+                            start_delimiter: BlockDelimiter::Syntax(string_syntax.string_delimiter),
+                            end_delimiter: BlockDelimiter::Syntax(string_syntax.string_delimiter),
+                            is_end_line: false,
+                            is_nested: false,
+                            string_syntax: Some(string_syntax),
+                        });
+                    }
+                    None => return CodeBlockType::None,
+                }
+
+                continue;
+            };
+
+            let content_start =
+                block_line_syntax.start_indexof + block_line_syntax.start_delimiter.len();
+
+            if !block_line_syntax.is_end_line {
+                if let Some(block_len) = block_line_syntax.find_end(&rest[content_start..], &mut 1)
+                {
+                    index += content_start + block_len;
+                    continue;
+                }
+            }
+
+            block_line_syntax.start_indexof += index;
+
+            return match is_comment {
+                true => CodeBlockType::Comment(block_line_syntax),
+                false => CodeBlockType::String(block_line_syntax),
+            };
+        }
+
+        return CodeBlockType::None;
+    }
+
+    // INFO: Doc comment line without the doc comment delimiter, like Rust `///` or Lua `---`.
+    // A delimiter followed by its own last char, like Rust `////`, is not a doc comment.
+    pub fn get_doc_comment_line(&self, line_number: usize, line: &str) -> Option<DocCommentLine> {
+        let content = line.trim_start();
+
+        for delimiter in self.doc_comment_delimiters {
+            let doc_comment = match content.strip_prefix(delimiter) {
+                Some(doc_comment) => doc_comment,
+                None => continue,
+            };
+
+            if doc_comment.starts_with(delimiter.chars().last()?) {
+                return None;
+            }
+
+            let mut column = line.len() - doc_comment.len();
+            let mut is_annotation = false;
+
+            if let Some(doc_comment_annotation) = self.doc_comment_annotation {
+                if let Some(description_start) = doc_comment_annotation(doc_comment) {
+                    column += description_start;
+                    is_annotation = true;
+                }
+            }
+
+            return Some(DocCommentLine {
+                line_number,
+                column,
+                content: line[column..].to_owned(),
+                is_annotation,
+            });
         }
 
-        if string_code_block_line_syntax.start_indexof
-            < comment_code_block_line_syntax.start_indexof
-            && string_code_block_line_syntax.start_indexof < comment_indexof
+        return None;
+    }
+
+    // INFO: Line that only has a line comment, without the comment delimiter and its repeated
+    // chars, like `//// text`. Lines that start a block comment, like Lua `--[[`, are not a line
+    // comment.
+    pub fn get_line_comment(&self, line_number: usize, line: &str) -> Option<LineComment> {
+        let content = line.trim_start();
+        let comment = content.strip_prefix(self.comment_delimiter)?;
+
+        if self
+            .block_comment
+            .get_code_block_line_syntax(content)
+            .start_indexof
+            == 0
         {
-            if !matches!(
-                string_code_block_line_syntax.end_delimiter.indexof(line),
-                None
-            ) {
-                return CodeBlockType::None;
+            return None;
+        }
+
+        let comment = comment.trim_start_matches(|c| self.comment_delimiter.contains(c));
+        let column = line.len() - comment.trim_start().len();
+
+        return Some(LineComment {
+            line_number,
+            column,
+            content: line[column..].trim_end().to_owned(),
+        });
+    }
+
+    pub fn is_preprocessor_directive(&self, line: &str) -> bool {
+        return match self.preprocessor_directive {
+            Some(directive) => line.trim_start().starts_with(directive),
+            None => false,
+        };
+    }
+
+    // INFO: Include paths like `<stdio.h>` are not words, so the path is removed from the line.
+    pub fn strip_preprocessor_include(&self, line: String) -> String {
+        let directive = match self.preprocessor_directive {
+            Some(directive) => directive,
+            None => return line,
+        };
+
+        let content = line.trim_start();
+        let after_directive = match content.strip_prefix(directive) {
+            Some(after_directive) => after_directive.trim_start(),
+            None => return line,
+        };
+
+        if !after_directive.starts_with("include") && !after_directive.starts_with("import") {
+            return line;
+        }
+
+        let path_start = match after_directive.find(['<', '"']) {
+            Some(path_start) => path_start,
+            None => return line,
+        };
+
+        let path_close = match after_directive.as_bytes()[path_start] {
+            b'<' => '>',
+            _ => '"',
+        };
+
+        let path_end = match after_directive[path_start + 1..].find(path_close) {
+            Some(path_end) => path_start + 1 + path_end + 1,
+            None => after_directive.len(),
+        };
+
+        let offset = line.len() - after_directive.len();
+
+        // INFO: The path is replaced with spaces, so the columns of a comment after it are kept.
+        return format!(
+            "{}{}{}",
+            &line[..offset + path_start],
+            " ".repeat(path_end - path_start),
+            &line[offset + path_end..]
+        );
+    }
+
+    // INFO: Replace the block comment line decoration and doc tags with whitespace, so the
+    // line keeps its length.
+    pub fn mask_block_comment_line(&self, line: &str) -> String {
+        let prefix = match self.block_comment_line_prefix {
+            Some(prefix) => prefix,
+            None => return line.to_owned(),
+        };
+
+        let content = line.trim_start();
+        let indent = line.len() - content.len();
+
+        if !content.starts_with(prefix) || self.block_comment.is_end_delimiter(content) {
+            return line.to_owned();
+        }
+
+        let mut masked = String::with_capacity(line.len());
+        masked.push_str(&line[..indent]);
+        masked.push_str(&" ".repeat(prefix.len()));
+        masked.push_str(&mask_doc_tag(&content[prefix.len()..]));
+
+        return masked;
+    }
+
+    // INFO: Returns the prefix (e.g. Python `f` or `rb`) right before the string delimiter at `string_indexof`.
+    pub fn get_string_prefix<'l>(&self, line: &'l str, string_indexof: usize) -> &'l str {
+        if self.string_prefixes.is_empty() || string_indexof > line.len() {
+            return "";
+        }
+
+        let before = &line[..string_indexof];
+        let prefix_len = before
+            .chars()
+            .rev()
+            .take_while(|c| self.string_prefixes.contains(*c))
+            .count();
+
+        let prefix_start = string_indexof - prefix_len;
+
+        // INFO: A prefix must not be the tail of an identifier, `elif"` is not a prefixed string.
+        if let Some(c) = before[..prefix_start].chars().last() {
+            if c.is_alphanumeric() || c == '_' {
+                return "";
             }
+        }
+
+        return &line[prefix_start..string_indexof];
+    }
 
-            return CodeBlockType::String(string_code_block_line_syntax);
+    pub fn get_string_interpolation(
+        &self,
+        prefix: &str,
+        string_delimiter: &BlockDelimiter,
+    ) -> Option<&StringInterpolationSyntax> {
+        return match &self.string_interpolation {
+            Some(interpolation)
+                if interpolation.is_interpolated(prefix, string_delimiter)
+                    || self.block_string.is_expanded_heredoc(string_delimiter) =>
+            {
+                Some(interpolation)
+            }
+            _ => None,
+        };
+    }
+}
+
+impl FormatPlaceholderSyntax {
+    // INFO: Length of the placeholder that starts at `start`, escaped delimiters like `{{` and
+    // `%%` are a placeholder of their own, so they are skipped.
+    fn find_len(&self, value: &str, start: usize) -> Option<usize> {
+        let rest = &value[start..];
+
+        return match self {
+            FormatPlaceholderSyntax::Brace => {
+                if rest.starts_with("{{") {
+                    return Some(2);
+                }
+
+                let rest = rest.strip_prefix('{')?;
+                let end = rest.find(['}', '{'])?;
+                let content = &rest[..end];
+
+                match rest[end..].starts_with('}')
+                    && content.len() <= 32
+                    && content
+                        .chars()
+                        .all(|c| c.is_alphanumeric() || "_.:?<>^+-#=$*!".contains(c))
+                {
+                    true => Some(end + 2),
+                    false => None,
+                }
+            }
+            FormatPlaceholderSyntax::Printf => {
+                if rest.starts_with("%%") {
+                    return Some(2);
+                }
+
+                let bytes = rest.strip_prefix('%')?.as_bytes();
+                let mut index = 0;
+
+                // INFO: Python mapping key, like `%(name)s`.
+                if bytes.first() == Some(&b'(') {
+                    index = bytes.iter().position(|b| *b == b')')? + 1;
+                }
+
+                while index < bytes.len() && b"-+#0'".contains(&bytes[index]) {
+                    index += 1;
+                }
+
+                while index < bytes.len() && (bytes[index].is_ascii_digit() || bytes[index] == b'*')
+                {
+                    index += 1;
+                }
+
+                if index < bytes.len() && bytes[index] == b'.' {
+                    index += 1;
+
+                    while index < bytes.len()
+                        && (bytes[index].is_ascii_digit() || bytes[index] == b'*')
+                    {
+                        index += 1;
+                    }
+                }
+
+                while index < bytes.len() && b"hlLqjzt".contains(&bytes[index]) {
+                    index += 1;
+                }
+
+                match index < bytes.len() && b"diouxXeEfFgGaAcspqvTtbOU".contains(&bytes[index]) {
+                    true => Some(index + 2),
+                    false => None,
+                }
+            }
+        };
+    }
+
+    fn is_escaped(&self, placeholder: &str) -> bool {
+        return placeholder == "{{" || placeholder == "%%";
+    }
+}
+
+// INFO: Describes expressions embedded inside of a string, like Python `f"{name}"`.
+// The string has to either start with one of the `string_prefixes` or use the `string_delimiter`.
+#[derive(Debug)]
+pub struct StringInterpolationSyntax {
+    pub string_prefixes: &'static str,
+    pub string_delimiter: DelimiterType,
+    pub start_delimiter: DelimiterType,
+    pub end_delimiter: DelimiterType,
+    // INFO: Variables without an end delimiter, like shell `$HOME`.
+    pub variable_prefix: Option<char>,
+}
+
+impl StringInterpolationSyntax {
+    // INFO: Interpolations are replaced by this char so the string keeps its length.
+    pub const MASK: char = '0';
+
+    pub fn is_interpolated(&self, prefix: &str, string_delimiter: &BlockDelimiter) -> bool {
+        if let BlockDelimiter::Syntax(string_delimiter) = string_delimiter {
+            if !matches!(self.string_delimiter, DelimiterType::None)
+                && self.string_delimiter == *string_delimiter
+            {
+                return true;
+            }
         }
 
-        return CodeBlockType::None;
+        return prefix.chars().any(|c| self.string_prefixes.contains(c));
+    }
+
+    // INFO: Split a string value into the prose, where every interpolation is masked,
+    // and the code that was inside of the interpolations.
+    pub fn split_code(&self, value: &str) -> (String, String) {
+        let start = self.start_delimiter.len();
+        let end = self.end_delimiter.len();
+        let bytes = value.as_bytes();
+
+        let mut prose = String::with_capacity(value.len());
+        let mut code = String::new();
+        let mut depth: usize = 0;
+        let mut code_start: usize = 0;
+        let mut index: usize = 0;
+
+        while index < bytes.len() {
+            if depth == 0 && (&self.start_delimiter) == (index, bytes) {
+                // INFO: Doubled start delimiter is an escaped literal, like `{{` in Python.
+                if start == 1 && index + 1 < bytes.len() && bytes[index + 1] == bytes[index] {
+                    prose.push_str(&value[index..index + 2]);
+                    index += 2;
+                    continue;
+                }
+
+                depth = 1;
+                code_start = index;
+                index += start;
+                continue;
+            }
+
+            if depth == 0 {
+                let variable_len = self.variable_len(value, index);
+
+                if 0 < variable_len {
+                    code.push_str(&value[index + 1..index + variable_len]);
+                    code.push(' ');
+
+                    for _ in value[index..index + variable_len].chars() {
+                        prose.push(StringInterpolationSyntax::MASK);
+                    }
+                    index += variable_len;
+                    continue;
+                }
+            }
+
+            if depth > 0 {
+                if (&self.end_delimiter) == (index, bytes) {
+                    depth -= 1;
+
+                    if depth == 0 {
+                        code.push_str(&value[code_start + start..index]);
+                        code.push(' ');
+
+                        index += end;
+                        for _ in value[code_start..index].chars() {
+                            prose.push(StringInterpolationSyntax::MASK);
+                        }
+                        continue;
+                    }
+                } else if (&self.start_delimiter) == (index, bytes) {
+                    depth += 1;
+                    index += start;
+                    continue;
+                }
+
+                index += 1;
+                continue;
+            }
+
+            let c = match value[index..].chars().next() {
+                Some(c) => c,
+                None => break,
+            };
+            prose.push(c);
+            index += c.len_utf8();
+        }
+
+        // INFO: Unclosed interpolation, keep it as is.
+        if depth > 0 {
+            prose.push_str(&value[code_start..]);
+        }
+
+        return (prose, code.trim_end().to_owned());
+    }
+
+    // INFO: Length of the variable at index, including the prefix. Special variables like `$1` or
+    // `$?` are one char long, an escaped prefix like `\$` is not a variable.
+    fn variable_len(&self, value: &str, index: usize) -> usize {
+        let bytes = value.as_bytes();
+
+        let variable_prefix = match self.variable_prefix {
+            Some(variable_prefix) => variable_prefix as u8,
+            None => return 0,
+        };
+
+        if bytes[index] != variable_prefix || (0 < index && bytes[index - 1] == b'\\') {
+            return 0;
+        }
+
+        let name_len = bytes[index + 1..]
+            .iter()
+            .take_while(|b| b.is_ascii_alphanumeric() || **b == b'_')
+            .count();
+
+        if 0 < name_len && !bytes[index + 1].is_ascii_digit() {
+            return name_len + 1;
+        }
+
+        return match bytes.get(index + 1) {
+            Some(b) if b.is_ascii_digit() || b"@*#?$!-".contains(b) => 2,
+            _ => 0,
+        };
     }
 }
 
@@ -318,27 +1618,482 @@ impl<const OPERATOR_COUNT: usize, const RESERVED_KEYWORD_COUNT: usize>
 pub struct ProgrammingStringSyntax {
     pub string_delimiter: DelimiterType,
     pub string_ignore_delimiter: [DelimiterType; 2],
+    // INFO: The string can span lines without a line continuation, like Rust `"..."`.
+    pub is_multi_line: bool,
+    // INFO: End of a line that continues the string on the next line, like Lua `\z`.
+    pub line_continuations: &'static [&'static str],
+}
+
+impl ProgrammingStringSyntax {
+    // INFO: Index after the string delimiter that ends the string. Escaped delimiters and escaped
+    // backslashes like `\\` do not end it.
+    pub fn find_end(&self, value: &str) -> Option<usize> {
+        let bytes = value.as_bytes();
+        let is_backslash_escape = self
+            .string_ignore_delimiter
+            .iter()
+            .any(|ignore_delimiter| matches!(ignore_delimiter, DelimiterType::DelimiterStr(s) if s.starts_with('\\')));
+        let mut index = 0;
+
+        while index < bytes.len() {
+            if is_backslash_escape && bytes[index] == b'\\' {
+                index += 2;
+                continue;
+            }
+
+            if let Some(ignore_delimiter) = self
+                .string_ignore_delimiter
+                .iter()
+                .find(|ignore_delimiter| *ignore_delimiter == (index, bytes))
+            {
+                index += ignore_delimiter.len();
+                continue;
+            }
+
+            if &self.string_delimiter == (index, bytes) {
+                return Some(index + self.string_delimiter.len());
+            }
+
+            index += 1;
+        }
+
+        return None;
+    }
+
+    // INFO: A string that is still open at the end of the line continues on the next line.
+    pub fn is_continued(&self, line: &str) -> bool {
+        return self.is_multi_line || self.find_line_continuation(line).is_some();
+    }
+
+    // INFO: Replace the line continuation with spaces, it is not part of the text.
+    pub fn mask_line_continuation(&self, line: &str) -> String {
+        let continuation_indexof = match self.find_line_continuation(line) {
+            Some(continuation_indexof) => continuation_indexof,
+            None => return line.to_owned(),
+        };
+        let content = line[..continuation_indexof].to_owned();
+
+        return content + &" ".repeat(line.len() - continuation_indexof);
+    }
+
+    fn find_line_continuation(&self, line: &str) -> Option<usize> {
+        let content = line.trim_end();
+
+        return self
+            .line_continuations
+            .iter()
+            .find(|line_continuation| content.ends_with(*line_continuation))
+            .map(|line_continuation| content.len() - line_continuation.len());
+    }
 }
 
 #[derive(Debug)]
 pub struct CodeBlockSyntax {
     start_delmiters: [DelimiterType; 4],
     end_delmiters: [DelimiterType; 4],
+    leveled_delimiter: Option<LeveledDelimiterSyntax>,
+    heredoc: Option<HeredocSyntax>,
+    // INFO: Blocks can be nested, like Rust `/* outer /* inner */ outer */`.
+    is_nested: bool,
+}
+
+// INFO: Delimiter where the end delimiter depends on the start delimiter level,
+// like C++ `R"tag(...)tag"`. The level is whatever is between `start_open` and `start_close`.
+#[derive(Debug)]
+pub struct LeveledDelimiterSyntax {
+    pub start_open: &'static str,
+    pub start_close: char,
+    pub end_open: &'static str,
+    pub end_close: &'static str,
+    // INFO: None will accept any char as level, except whitespace, `\\` and the parentheses.
+    pub level_char: Option<char>,
+    pub min_level: usize,
+    pub max_level: usize,
+}
+
+impl LeveledDelimiterSyntax {
+    // INFO: Find the first leveled start delimiter and return its index, start and end delimiter.
+    pub fn find(&self, value: &str) -> Option<(usize, String, String)> {
+        let mut search_from = 0;
+
+        while let Some(indexof) = value[search_from..].find(self.start_open) {
+            let start_indexof = search_from + indexof;
+            let level_start = start_indexof + self.start_open.len();
+            let rest = &value[level_start..];
+
+            let level_len = match self.level_char {
+                Some(level_char) => rest.len() - rest.trim_start_matches(level_char).len(),
+                None => rest
+                    .find(|c: char| {
+                        c == self.start_close
+                            || c.is_whitespace()
+                            || c == '\\'
+                            || c == '('
+                            || c == ')'
+                    })
+                    .unwrap_or(rest.len()),
+            };
+
+            if self.min_level <= level_len
+                && level_len <= self.max_level
+                && rest[level_len..].starts_with(self.start_close)
+            {
+                let level = &rest[..level_len];
+                let start_delimiter = format!("{}{}{}", self.start_open, level, self.start_close);
+                let end_delimiter = format!("{}{}{}", self.end_open, level, self.end_close);
+
+                return Some((start_indexof, start_delimiter, end_delimiter));
+            }
+
+            search_from = level_start;
+        }
+
+        return None;
+    }
+}
+
+// INFO: Block where the end delimiter is a word from the start line, like shell `<<EOF`.
+// The end delimiter has to be the whole line, `<<-EOF` allows it to be indented.
+#[derive(Debug)]
+pub struct HeredocSyntax {
+    pub start_open: &'static str,
+}
+
+impl HeredocSyntax {
+    // INFO: Find the first heredoc start delimiter and return its index, start and end delimiter.
+    pub fn find(&self, value: &str) -> Option<(usize, String, String)> {
+        let mut search_from = 0;
+
+        while let Some(indexof) = value[search_from..].find(self.start_open) {
+            let start_indexof = search_from + indexof;
+            let mut word_start = start_indexof + self.start_open.len();
+            search_from = word_start;
+
+            // INFO: Here strings like `<<<` are not a heredoc.
+            if value[word_start..].starts_with('<')
+                || (0 < start_indexof && value.as_bytes()[start_indexof - 1] == b'<')
+            {
+                continue;
+            }
+
+            if value[word_start..].starts_with('-') {
+                word_start += 1;
+            }
+
+            word_start += value[word_start..].len() - value[word_start..].trim_start().len();
+
+            let quote = match value[word_start..].chars().next() {
+                Some(c) if c == '\'' || c == '"' => {
+                    word_start += 1;
+                    Some(c)
+                }
+                _ => None,
+            };
+
+            let word_len = value[word_start..]
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(value.len() - word_start);
+            let mut word_end = word_start + word_len;
+
+            if word_len == 0 || value.as_bytes()[word_start].is_ascii_digit() {
+                continue;
+            }
+
+            if let Some(quote) = quote {
+                if !value[word_end..].starts_with(quote) {
+                    continue;
+                }
+
+                word_end += 1;
+            }
+
+            let start_delimiter = value[start_indexof..word_end].to_owned();
+            let end_delimiter = value[word_start..word_start + word_len].to_owned();
+
+            return Some((start_indexof, start_delimiter, end_delimiter));
+        }
+
+        return None;
+    }
+
+    // INFO: Variables are expanded in heredocs without a quoted word.
+    pub fn is_expanded(&self, start_delimiter: &BlockDelimiter) -> bool {
+        return match start_delimiter {
+            BlockDelimiter::Parsed(s) => {
+                s.starts_with(self.start_open) && !s.contains('\'') && !s.contains('"')
+            }
+            BlockDelimiter::Syntax(_) => false,
+        };
+    }
+}
+
+// INFO: Delimiter of a block in a line. Delimiters that are parsed from the line, like Lua `[==[`
+// or shell `<<EOF`, are owned by the block.
+#[derive(Debug, Clone)]
+pub enum BlockDelimiter {
+    Syntax(DelimiterType),
+    Parsed(String),
+}
+
+impl BlockDelimiter {
+    pub fn len(&self) -> usize {
+        return match self {
+            BlockDelimiter::Syntax(delimiter) => delimiter.len(),
+            BlockDelimiter::Parsed(delimiter) => delimiter.len(),
+        };
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.len() == 0;
+    }
+
+    // INFO: The delimiter when it is a string, chars and no delimiter are None.
+    pub fn as_str(&self) -> Option<&str> {
+        return match self {
+            BlockDelimiter::Syntax(DelimiterType::DelimiterStr(delimiter)) => Some(delimiter),
+            BlockDelimiter::Syntax(_) => None,
+            BlockDelimiter::Parsed(delimiter) => Some(delimiter),
+        };
+    }
+
+    pub fn indexof(&self, value: &str) -> Option<usize> {
+        return match self {
+            BlockDelimiter::Syntax(delimiter) => delimiter.indexof(value),
+            BlockDelimiter::Parsed(delimiter) => value.find(delimiter.as_str()),
+        };
+    }
+
+    // INFO: Remove one delimiter at the end, an escaped quote before it is part of the value.
+    pub fn trim_end<'v>(&self, value: &'v str) -> &'v str {
+        let trimmed = match self {
+            BlockDelimiter::Syntax(DelimiterType::DelimiterChar(delimiter)) => {
+                value.strip_suffix(*delimiter)
+            }
+            _ => match self.as_str() {
+                Some(delimiter) => value.strip_suffix(delimiter),
+                None => None,
+            },
+        };
+
+        return trimmed.unwrap_or(value);
+    }
 }
 
 #[derive(Debug)]
 pub struct CodeBlockLineSyntax {
     pub start_indexof: usize,
-    pub start_delimiter: DelimiterType,
-    pub end_delimiter: DelimiterType,
+    pub start_delimiter: BlockDelimiter,
+    pub end_delimiter: BlockDelimiter,
+    // INFO: The block starts on the next line and the end delimiter is the whole line.
+    pub is_end_line: bool,
+    pub is_nested: bool,
+    // INFO: Normal strings that span lines, like Rust `"..."`, end at an unescaped delimiter.
+    pub string_syntax: Option<&'static ProgrammingStringSyntax>,
+}
+
+// INFO: Mask doc tags like `@param {string} name`, only the description is prose.
+fn mask_doc_tag(content: &str) -> String {
+    const NAMED_TAGS: &[&str] = &[
+        "@param",
+        "@arg",
+        "@argument",
+        "@property",
+        "@prop",
+        "@typedef",
+        "@callback",
+    ];
+
+    let description = content.trim_start();
+
+    if !description.starts_with('@') {
+        return content.to_owned();
+    }
+
+    let tag_len = description
+        .find(char::is_whitespace)
+        .unwrap_or(description.len());
+    let tag = &description[..tag_len];
+    let mut masked_len = content.len() - description.len() + tag_len;
+    let mut rest = &content[masked_len..];
+
+    // INFO: Type expression, like `{string|number}`
+    let trimmed = rest.trim_start();
+    if trimmed.starts_with('{') {
+        let mut depth = 0;
+        for (index, c) in trimmed.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' => depth -= 1,
+                _ => {}
+            }
+
+            if depth == 0 {
+                masked_len += rest.len() - trimmed.len() + index + 1;
+                break;
+            }
+        }
+        rest = &content[masked_len..];
+    }
+
+    if NAMED_TAGS.contains(&tag) {
+        let trimmed = rest.trim_start();
+        let name_len = trimmed.find(char::is_whitespace).unwrap_or(trimmed.len());
+        masked_len += rest.len() - trimmed.len() + name_len;
+    }
+
+    let mut masked = " ".repeat(content[..masked_len].chars().count());
+    masked.push_str(&content[masked_len..]);
+
+    return masked;
+}
+
+impl CodeBlockLineSyntax {
+    // INFO: Look for the end delimiter after the start delimiter, so blocks like Python `"""`
+    // that start and end with the same delimiter are not closed by their own start.
+    pub fn is_end_in_line(&self, line: &str) -> bool {
+        if self.is_end_line {
+            return false;
+        }
+
+        let after_start = self
+            .start_indexof
+            .saturating_add(self.start_delimiter.len());
+
+        if after_start > line.len() || !line.is_char_boundary(after_start) {
+            return false;
+        }
+
+        return !matches!(self.find_end(&line[after_start..], &mut 1), None);
+    }
+
+    // INFO: Index after the end delimiter that closes the block. `depth` is the count of open
+    // blocks before `value`, in nested blocks every start delimiter opens one more block.
+    pub fn find_end(&self, value: &str, depth: &mut usize) -> Option<usize> {
+        if let Some(string_syntax) = self.string_syntax {
+            return string_syntax.find_end(value);
+        }
+
+        let (start, end) = match (self.start_delimiter.as_str(), self.end_delimiter.as_str()) {
+            (Some(start), Some(end)) if self.is_nested => (start, end),
+            _ => {
+                return self
+                    .end_delimiter
+                    .indexof(value)
+                    .map(|indexof| indexof + self.end_delimiter.len());
+            }
+        };
+
+        let bytes = value.as_bytes();
+        let mut index = 0;
+
+        while index < bytes.len() {
+            if bytes[index..].starts_with(end.as_bytes()) {
+                index += end.len();
+                *depth = depth.saturating_sub(1);
+
+                if *depth == 0 {
+                    return Some(index);
+                }
+            } else if bytes[index..].starts_with(start.as_bytes()) {
+                index += start.len();
+                *depth += 1;
+            } else {
+                index += 1;
+            }
+        }
+
+        return None;
+    }
+
+    // INFO: A block that is not closed in the line continues, unless it is a normal string
+    // without a line continuation.
+    pub fn is_continued(&self, line: &str) -> bool {
+        return match self.string_syntax {
+            Some(string_syntax) => string_syntax.is_continued(line),
+            None => true,
+        };
+    }
+
+    pub fn mask_line_continuation(&self, line: &str) -> String {
+        return match self.string_syntax {
+            Some(string_syntax) => string_syntax.mask_line_continuation(line),
+            None => line.to_owned(),
+        };
+    }
+
+    // INFO: Replace the start and end delimiters of nested blocks with spaces, so they are not
+    // part of the text. With `is_end` the last end delimiter closes the block and is kept.
+    pub fn mask_nested_delimiters(&self, value: &str, is_end: bool) -> String {
+        if !self.is_nested {
+            return value.to_owned();
+        }
+
+        let content_end = match is_end {
+            true => value.len().saturating_sub(self.end_delimiter.len()),
+            false => value.len(),
+        };
+        let mut masked = value[..content_end].to_owned();
+
+        for delimiter in [&self.start_delimiter, &self.end_delimiter] {
+            if let Some(delimiter) = delimiter.as_str() {
+                masked = masked.replace(delimiter, &" ".repeat(delimiter.len()));
+            }
+        }
+
+        masked.push_str(&value[content_end..]);
+
+        return masked;
+    }
 }
 
 impl CodeBlockSyntax {
+    // INFO: Up to four start and end delimiter pairs, the rest is ignored.
+    pub fn new(delimiters: &[(DelimiterType, DelimiterType)]) -> Self {
+        let mut start_delmiters = [DelimiterType::None; 4];
+        let mut end_delmiters = [DelimiterType::None; 4];
+
+        for (index, (start, end)) in delimiters.iter().take(4).enumerate() {
+            start_delmiters[index] = *start;
+            end_delmiters[index] = *end;
+        }
+
+        return Self {
+            start_delmiters,
+            end_delmiters,
+            leveled_delimiter: None,
+            heredoc: None,
+            is_nested: false,
+        };
+    }
+
+    pub fn is_expanded_heredoc(&self, start_delimiter: &BlockDelimiter) -> bool {
+        return match &self.heredoc {
+            Some(heredoc) => heredoc.is_expanded(start_delimiter),
+            None => false,
+        };
+    }
+
+    pub fn is_end_delimiter(&self, value: &str) -> bool {
+        for end_delimiter in &self.end_delmiters {
+            let is_end = match end_delimiter {
+                DelimiterType::DelimiterStr(s) => value.starts_with(s),
+                DelimiterType::DelimiterChar(c) => value.starts_with(*c),
+                DelimiterType::None => false,
+            };
+
+            if is_end {
+                return true;
+            }
+        }
+
+        return false;
+    }
+
     pub fn get_code_block_line_syntax(&self, value: &str) -> CodeBlockLineSyntax {
         let mut index = 0;
         let mut indexof = usize::MAX;
-        let mut start_delimiter_type = DelimiterType::None;
-        let mut end_delimiter_type = DelimiterType::None;
+        let mut start_delimiter_type = BlockDelimiter::Syntax(DelimiterType::None);
+        let mut end_delimiter_type = BlockDelimiter::Syntax(DelimiterType::None);
         for start_delimiter in &self.start_delmiters {
             if indexof != usize::MAX {
                 break;
@@ -347,13 +2102,13 @@ impl CodeBlockSyntax {
             match start_delimiter {
                 DelimiterType::DelimiterStr(s) => {
                     indexof = value.find(s).unwrap_or(indexof);
-                    start_delimiter_type = *start_delimiter;
-                    end_delimiter_type = self.end_delmiters[index];
+                    start_delimiter_type = BlockDelimiter::Syntax(*start_delimiter);
+                    end_delimiter_type = BlockDelimiter::Syntax(self.end_delmiters[index]);
                 }
                 DelimiterType::DelimiterChar(c) => {
                     indexof = value.find(*c).unwrap_or(indexof);
-                    start_delimiter_type = *start_delimiter;
-                    end_delimiter_type = self.end_delmiters[index];
+                    start_delimiter_type = BlockDelimiter::Syntax(*start_delimiter);
+                    end_delimiter_type = BlockDelimiter::Syntax(self.end_delmiters[index]);
                 }
                 DelimiterType::None => break,
             }
@@ -361,10 +2116,36 @@ impl CodeBlockSyntax {
             index += 1;
         }
 
+        if let Some(leveled_delimiter) = &self.leveled_delimiter {
+            if let Some((leveled_indexof, start, end)) = leveled_delimiter.find(value) {
+                if leveled_indexof < indexof {
+                    indexof = leveled_indexof;
+                    start_delimiter_type = BlockDelimiter::Parsed(start);
+                    end_delimiter_type = BlockDelimiter::Parsed(end);
+                }
+            }
+        }
+
+        let mut is_end_line = false;
+
+        if let Some(heredoc) = &self.heredoc {
+            if let Some((heredoc_indexof, start, end)) = heredoc.find(value) {
+                if heredoc_indexof < indexof {
+                    indexof = heredoc_indexof;
+                    start_delimiter_type = BlockDelimiter::Parsed(start);
+                    end_delimiter_type = BlockDelimiter::Parsed(end);
+                    is_end_line = true;
+                }
+            }
+        }
+
         return CodeBlockLineSyntax {
             start_indexof: indexof,
             start_delimiter: start_delimiter_type,
             end_delimiter: end_delimiter_type,
+            is_end_line,
+            is_nested: self.is_nested,
+            string_syntax: None,
         };
     }
 }
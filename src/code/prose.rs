@@ -0,0 +1,105 @@
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+// INFO: Part of the prose value and where it came from in the original file.
+#[derive(Debug, Clone)]
+pub struct ProseSpan {
+    pub offset: usize,
+    pub line_number: usize,
+    pub column: usize,
+    pub len: usize,
+}
+
+// INFO: Text that is checked as a whole, like a Markdown paragraph. Every part of the value that
+// is taken from the file has a span, so a match offset can be mapped back to an exact line and
+// column. Separators and masks have no span.
+#[derive(Debug, Default)]
+pub struct Prose {
+    pub hash: u64,
+    pub value: String,
+    pub spans: Vec<ProseSpan>,
+    // INFO: Byte ranges of format placeholders in prose taken from strings, like `{path}`.
+    pub placeholders: Vec<(usize, usize)>,
+}
+
+impl Prose {
+    // INFO: Masked values like URLs and inline code are replaced by this char.
+    pub const MASK: char = '0';
+
+    pub fn new() -> Self {
+        return Self {
+            hash: 0,
+            value: String::new(),
+            spans: Vec::new(),
+            placeholders: Vec::new(),
+        };
+    }
+
+    pub fn push_str(&mut self, value: &str, line_number: usize, column: usize) {
+        if value.is_empty() {
+            return;
+        }
+
+        // INFO: Extend the last span when the value continues on the same line.
+        if let Some(last_span) = self.spans.last_mut() {
+            if last_span.line_number == line_number
+                && last_span.column + last_span.len == column
+                && last_span.offset + last_span.len == self.value.len()
+            {
+                last_span.len += value.len();
+                self.value.push_str(value);
+                return;
+            }
+        }
+
+        self.spans.push(ProseSpan {
+            offset: self.value.len(),
+            line_number,
+            column,
+            len: value.len(),
+        });
+        self.value.push_str(value);
+    }
+
+    pub fn push_mask(&mut self, len: usize) {
+        for _ in 0..len {
+            self.value.push(Prose::MASK);
+        }
+    }
+
+    // INFO: Push a space, unless the value is empty or already ends with whitespace.
+    pub fn push_separator(&mut self) {
+        match self.value.chars().last() {
+            Some(c) if !c.is_whitespace() => self.value.push(' '),
+            _ => {}
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return !self.value.chars().any(|c| c.is_alphabetic());
+    }
+
+    pub fn finish(mut self) -> Self {
+        let value = self.value.trim_end().len();
+        self.value.truncate(value);
+
+        let mut hasher = DefaultHasher::new();
+        self.value.hash(&mut hasher);
+        self.hash = hasher.finish();
+
+        return self;
+    }
+
+    // INFO: Map a byte range in the value to the line number, start column and end column.
+    // A range that continues into the next span is cut at the end of the first span.
+    pub fn position(&self, start: usize, end: usize) -> Option<(usize, usize, usize)> {
+        let span = self
+            .spans
+            .iter()
+            .find(|span| span.offset <= start && start < span.offset + span.len)?;
+
+        let start_column = span.column + (start - span.offset);
+        let end_column = span.column + (end.min(span.offset + span.len) - span.offset);
+
+        return Some((span.line_number, start_column, end_column));
+    }
+}
@@ -0,0 +1,402 @@
+use super::{
+    markdown::{push_inline, push_prose},
+    prose::Prose,
+};
+
+// INFO: Attribute strings that are user-facing text, as attribute path and key. The key is the
+// attribute path for `#[doc = "..."]` and the first argument like `#[error("...")]`.
+const PROSE_ATTRIBUTES: [(&str, &str); 10] = [
+    ("doc", "doc"),
+    ("deprecated", "deprecated"),
+    ("deprecated", "note"),
+    ("error", "error"),
+    ("arg", "help"),
+    ("arg", "long_help"),
+    ("command", "about"),
+    ("command", "long_about"),
+    ("clap", "help"),
+    ("clap", "about"),
+];
+
+// INFO: Macros where the string is a path or a name, not prose.
+const PATH_MACROS: [&str; 5] = [
+    "include_str!(",
+    "include_bytes!(",
+    "include!(",
+    "env!(",
+    "option_env!(",
+];
+
+// INFO: An attribute that is not closed at the end of the line, like `#[error(` with the message
+// on the next line. The path and depth are kept to find the prose on the next lines.
+#[derive(Debug, Clone)]
+pub struct OpenAttribute {
+    path: String,
+    depth: usize,
+    is_first_argument: bool,
+}
+
+impl OpenAttribute {
+    // INFO: The attribute that starts with `#[` or `#![` at `start`, and the index after its path.
+    fn new(line: &str, start: usize) -> (Self, usize) {
+        let bytes = line.as_bytes();
+        let path_start = start + line[start..].find('[').unwrap_or(0) + 1;
+        let path_start = skip_whitespace(line, path_start);
+        let path_end = path_start
+            + bytes[path_start..]
+                .iter()
+                .take_while(|b| is_ident(**b))
+                .count();
+
+        let attribute = OpenAttribute {
+            path: line[path_start..path_end].to_owned(),
+            depth: 0,
+            is_first_argument: false,
+        };
+
+        return (attribute, path_end);
+    }
+}
+
+// INFO: Mask the parts of a Rust line that are code or configuration, so they are not split into
+// strings and words. Attributes like `#[serde(rename = "name")]`, byte strings, `include_str!`
+// paths, char literals and lifetimes are replaced by spaces, so the columns are kept.
+pub fn mask_code_literals(line: String) -> String {
+    let bytes = line.as_bytes();
+    let mut masked = bytes.to_vec();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let literal_start = index;
+
+        index = match bytes[index] {
+            b'/' if bytes.get(index + 1) == Some(&b'/') => break,
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                match find_block_comment_end(&line, index) {
+                    Some(comment_end) => comment_end,
+                    None => break,
+                }
+            }
+            // INFO: The next lines of a multi line attribute are masked with its prose, see
+            // `generate_attribute_proses`.
+            b'#' if line[index + 1..].starts_with('[') || line[index + 1..].starts_with("![") => {
+                let attribute_end = find_attribute_end(&line, index);
+                mask(&mut masked, literal_start, attribute_end);
+                attribute_end
+            }
+            b'r' if !is_previous_ident(bytes, index) && is_raw_string_start(&line[index..]) => {
+                find_raw_string_end(&line, index)
+            }
+            b'b' if !is_previous_ident(bytes, index)
+                && matches!(bytes.get(index + 1), Some(b'"' | b'\'' | b'r')) =>
+            {
+                let literal_end = match bytes[index + 1] {
+                    b'"' => find_string_end(&line, index + 1),
+                    b'\'' => find_char_end(&line, index + 1).unwrap_or(index + 2),
+                    _ if is_raw_string_start(&line[index + 1..]) => {
+                        find_raw_string_end(&line, index + 1)
+                    }
+                    _ => index + 1,
+                };
+
+                if index + 1 < literal_end {
+                    mask(&mut masked, literal_start, literal_end);
+                }
+
+                literal_end
+            }
+            b'"' => {
+                let string_end = find_string_end(&line, index);
+                let before = line[..index].trim_end();
+
+                if PATH_MACROS
+                    .iter()
+                    .any(|path_macro| before.ends_with(path_macro))
+                {
+                    mask(&mut masked, literal_start, string_end);
+                }
+
+                string_end
+            }
+            // INFO: A char literal like `'a'` or `'\n'`, otherwise a lifetime or label like `'a`.
+            b'\'' => {
+                let literal_end = match find_char_end(&line, index) {
+                    Some(char_end) => char_end,
+                    None => {
+                        index
+                            + 1
+                            + bytes[index + 1..]
+                                .iter()
+                                .take_while(|b| is_ident(**b))
+                                .count()
+                    }
+                };
+
+                mask(&mut masked, literal_start, literal_end);
+                literal_end
+            }
+            _ => index + 1,
+        };
+    }
+
+    return match String::from_utf8(masked) {
+        Ok(masked) => masked,
+        Err(_) => line,
+    };
+}
+
+// INFO: Prose of attributes like `#[doc = "..."]`, `#[deprecated(note = "...")]`,
+// `#[error("...")]` or `#[arg(help = "...")]`. The rest of the attribute is masked as code. The
+// part of an attribute that continues from the previous line is masked in the returned line, so
+// its strings are not checked as code.
+pub fn generate_attribute_proses(
+    line_number: usize,
+    line: String,
+    open_attribute: &mut Option<OpenAttribute>,
+) -> (Vec<Prose>, String) {
+    let bytes = line.as_bytes();
+    let mut proses = Vec::new();
+    let mut index = 0;
+
+    if let Some(attribute) = open_attribute.take() {
+        index = push_attribute_proses(
+            &mut proses,
+            line_number,
+            &line,
+            0,
+            attribute,
+            open_attribute,
+        );
+    }
+
+    let continued_end = index;
+
+    while index < bytes.len() {
+        index = match bytes[index] {
+            b'/' if bytes.get(index + 1) == Some(&b'/') => break,
+            b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                match find_block_comment_end(&line, index) {
+                    Some(comment_end) => comment_end,
+                    None => break,
+                }
+            }
+            b'#' if line[index + 1..].starts_with('[') || line[index + 1..].starts_with("![") => {
+                let (attribute, path_end) = OpenAttribute::new(&line, index);
+                push_attribute_proses(
+                    &mut proses,
+                    line_number,
+                    &line,
+                    path_end,
+                    attribute,
+                    open_attribute,
+                )
+            }
+            b'"' => find_string_end(&line, index),
+            _ => index + 1,
+        };
+    }
+
+    if continued_end == 0 {
+        return (proses, line);
+    }
+
+    let masked = " ".repeat(continued_end) + &line[continued_end..];
+
+    return (proses, masked);
+}
+
+// INFO: Push the prose of the attribute from `start` and return the index after the attribute.
+// The attribute is kept in `open_attribute` when it continues on the next line.
+fn push_attribute_proses(
+    proses: &mut Vec<Prose>,
+    line_number: usize,
+    line: &str,
+    start: usize,
+    mut attribute: OpenAttribute,
+    open_attribute: &mut Option<OpenAttribute>,
+) -> usize {
+    let bytes = line.as_bytes();
+    let mut argument_start = start;
+    let mut index = start;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'(' | b'[' => {
+                attribute.depth += 1;
+                attribute.is_first_argument = attribute.depth == 1;
+                argument_start = index + 1;
+            }
+            b']' if attribute.depth == 0 => return index + 1,
+            b')' | b']' => attribute.depth -= 1,
+            b',' => {
+                attribute.is_first_argument = false;
+                argument_start = index + 1;
+            }
+            b'"' => {
+                let string_end = find_string_end(line, index);
+                let before = line[argument_start..index].trim();
+                let path = attribute.path.as_str();
+
+                let key = match before.strip_suffix('=') {
+                    Some(_) if attribute.depth == 0 => Some(path),
+                    Some(before) if attribute.depth == 1 => {
+                        let before = before.trim_end();
+                        let key_len = before.bytes().rev().take_while(|b| is_ident(*b)).count();
+                        Some(&before[before.len() - key_len..])
+                    }
+                    None if attribute.depth == 1
+                        && attribute.is_first_argument
+                        && before.is_empty() =>
+                    {
+                        Some(path)
+                    }
+                    _ => None,
+                };
+
+                if let Some(key) = key {
+                    if PROSE_ATTRIBUTES.contains(&(path, key)) && index + 1 < string_end {
+                        let mut prose = Prose::new();
+                        push_inline(&mut prose, line, line_number, index + 1, string_end - 1);
+                        push_prose(proses, &mut prose);
+                    }
+                }
+
+                index = string_end;
+                continue;
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    *open_attribute = Some(attribute);
+
+    return bytes.len();
+}
+
+// INFO: Index after the `*/` that closes the block comment, block comments can be nested.
+fn find_block_comment_end(line: &str, start: usize) -> Option<usize> {
+    let bytes = line.as_bytes();
+    let mut depth = 0;
+    let mut index = start;
+
+    while index + 1 < bytes.len() {
+        match &bytes[index..index + 2] {
+            b"/*" => depth += 1,
+            b"*/" => depth -= 1,
+            _ => {
+                index += 1;
+                continue;
+            }
+        }
+
+        index += 2;
+
+        if depth == 0 {
+            return Some(index);
+        }
+    }
+
+    return None;
+}
+
+fn skip_whitespace(line: &str, start: usize) -> usize {
+    return line.len() - line[start..].trim_start().len();
+}
+
+fn mask(masked: &mut [u8], start: usize, end: usize) {
+    for byte in &mut masked[start..end] {
+        *byte = b' ';
+    }
+}
+
+fn is_ident(byte: u8) -> bool {
+    return byte.is_ascii_alphanumeric() || byte == b'_';
+}
+
+fn is_previous_ident(bytes: &[u8], index: usize) -> bool {
+    return 0 < index && is_ident(bytes[index - 1]);
+}
+
+fn is_raw_string_start(value: &str) -> bool {
+    return value
+        .strip_prefix('r')
+        .is_some_and(|value| value.trim_start_matches('#').starts_with('"'));
+}
+
+// INFO: Index after the closing quote, escaped quotes are part of the string.
+fn find_string_end(line: &str, start: usize) -> usize {
+    let bytes = line.as_bytes();
+    let mut index = start + 1;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'\\' => index += 2,
+            b'"' => return index + 1,
+            _ => index += 1,
+        }
+    }
+
+    return bytes.len();
+}
+
+// INFO: Index after a raw string like `r"C:\path"` or `r#"a "quote""#`.
+fn find_raw_string_end(line: &str, start: usize) -> usize {
+    let hashes = line[start + 1..].bytes().take_while(|b| *b == b'#').count();
+    let content_start = start + 1 + hashes + 1;
+    let end_delimiter = format!("\"{}", "#".repeat(hashes));
+
+    return match line[content_start..].find(&end_delimiter) {
+        Some(content_len) => content_start + content_len + end_delimiter.len(),
+        None => line.len(),
+    };
+}
+
+// INFO: Index after a char literal like `'a'`, `'\''` or `'\u{1F600}'`.
+fn find_char_end(line: &str, start: usize) -> Option<usize> {
+    let rest = &line[start + 1..];
+
+    if rest.starts_with('\\') {
+        let escape_len = rest.get(2..)?.find('\'')?;
+        return Some(start + 1 + 2 + escape_len + 1);
+    }
+
+    let c = rest.chars().next()?;
+
+    if c != '\'' && rest[c.len_utf8()..].starts_with('\'') {
+        return Some(start + 1 + c.len_utf8() + 1);
+    }
+
+    return None;
+}
+
+// INFO: Index after the closing bracket of the attribute, strings in the attribute can have
+// brackets.
+fn find_attribute_end(line: &str, start: usize) -> usize {
+    let bytes = line.as_bytes();
+    let mut depth = 0;
+    let mut index = start + 1;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            b'"' => {
+                index = find_string_end(line, index);
+                continue;
+            }
+            b'[' => depth += 1,
+            b']' => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return index + 1;
+                }
+            }
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    return bytes.len();
+}
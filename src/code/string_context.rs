@@ -0,0 +1,246 @@
+use std::sync::RwLock;
+
+use log::warn;
+
+// INFO: Who reads a string literal, found from the call or the position the string is in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StringContext {
+    // INFO: Shown to the user, like `println!("...")`, `vim.notify("...")` or `error("...")`.
+    UserFacing,
+    // INFO: Written to a log, like `info!("...")`, `console.log("...")` or `logger.warning("...")`.
+    LogMessage,
+    // INFO: Read by the program, like `require("...")`, `map["..."]` or `{ "...": value }`.
+    InternalKey,
+    Unknown,
+}
+
+impl StringContext {
+    pub fn from_name(name: &str) -> Option<Self> {
+        return match name {
+            "user_facing" => Some(StringContext::UserFacing),
+            "log_message" => Some(StringContext::LogMessage),
+            "internal_key" => Some(StringContext::InternalKey),
+            "unknown" => Some(StringContext::Unknown),
+            _ => None,
+        };
+    }
+}
+
+const USER_FACING_CALLS: [&str; 35] = [
+    "println!",
+    "print!",
+    "eprintln!",
+    "eprint!",
+    "panic!",
+    "anyhow!",
+    "bail!",
+    "ensure!",
+    "unreachable!",
+    "unimplemented!",
+    "todo!",
+    "assert!",
+    "assert_eq!",
+    "assert_ne!",
+    "expect",
+    "print",
+    "printf",
+    "puts",
+    "error",
+    "assert",
+    "alert",
+    "confirm",
+    "prompt",
+    "notify",
+    "notify_once",
+    "nvim_err_writeln",
+    "Print",
+    "Println",
+    "Printf",
+    "Fprintf",
+    "Fprintln",
+    "Errorf",
+    "_",
+    "gettext",
+    "ngettext",
+];
+
+const LOG_MACROS: [&str; 6] = ["error!", "warn!", "info!", "debug!", "trace!", "log!"];
+
+// INFO: Receivers of log calls, like `log.info`, `logger.warning` or `console.error`.
+const LOG_RECEIVERS: [&str; 5] = ["log", "logger", "logging", "console", "slog"];
+
+// INFO: Calls where the first argument is a key, like `require("...")` or `map.get("...")`.
+const INTERNAL_KEY_CALLS: [&str; 21] = [
+    "require",
+    "import",
+    "getenv",
+    "Getenv",
+    "LookupEnv",
+    "var",
+    "var_os",
+    "get",
+    "get_mut",
+    "insert",
+    "contains_key",
+    "remove",
+    "entry",
+    "getattr",
+    "hasattr",
+    "setattr",
+    "getElementById",
+    "querySelector",
+    "querySelectorAll",
+    "getItem",
+    "setItem",
+];
+
+// INFO: Calls that build a string, the string is read by the call around them.
+const FORMAT_CALLS: [&str; 7] = [
+    "format!", "format", "concat!", "from", "Sprintf", "sprintf", "tostring",
+];
+
+// INFO: Contexts set by the user, every context is checked when it is None.
+static CHECKED_STRING_CONTEXTS: RwLock<Option<Vec<StringContext>>> = RwLock::new(None);
+
+pub fn set_checked_string_contexts(names: &[String]) {
+    let mut checked_string_contexts = match CHECKED_STRING_CONTEXTS.write() {
+        Ok(checked_string_contexts) => checked_string_contexts,
+        Err(e) => e.into_inner(),
+    };
+
+    let mut contexts = Vec::with_capacity(names.len());
+
+    for name in names {
+        match StringContext::from_name(name) {
+            Some(context) => contexts.push(context),
+            None => warn!("Unknown string context: {}", name),
+        }
+    }
+
+    *checked_string_contexts = Some(contexts);
+}
+
+pub fn is_checked(context: &StringContext) -> bool {
+    let checked_string_contexts = match CHECKED_STRING_CONTEXTS.read() {
+        Ok(checked_string_contexts) => checked_string_contexts,
+        Err(e) => e.into_inner(),
+    };
+
+    return match checked_string_contexts.as_deref() {
+        Some(contexts) => contexts.contains(context),
+        None => true,
+    };
+}
+
+// INFO: Context of a string literal, from the code `before` and `after` it on the line. Other
+// strings on the line are already masked, so their brackets are not counted.
+pub fn classify(before: &str, after: &str) -> StringContext {
+    let before = before.trim_end();
+    let after = after.trim_start();
+
+    // INFO: Index like `map["key"]`, a key like `{ "key": value }` or a match arm like `"key" =>`.
+    if (before.ends_with('[') && after.starts_with(']'))
+        || ((before.ends_with('{') || before.ends_with(','))
+            && after.starts_with(':')
+            && !after.starts_with("::"))
+        || after.starts_with("=>")
+    {
+        return StringContext::InternalKey;
+    }
+
+    // INFO: Call without parentheses, like Lua `require "module"`.
+    let callee = get_callee(before);
+    if !callee.is_empty() {
+        if let Some(context) = classify_callee(callee, true) {
+            return context;
+        }
+    }
+
+    let mut index = before.len();
+    let mut is_first_argument = true;
+
+    while let Some(open_indexof) = find_open_paren(&before[..index], &mut is_first_argument) {
+        let callee = get_callee(&before[..open_indexof]);
+        let is_format_call = FORMAT_CALLS.contains(&get_segments(callee).0);
+
+        if !is_format_call {
+            return classify_callee(callee, is_first_argument).unwrap_or(StringContext::Unknown);
+        }
+
+        // INFO: The string is formatted for the call around it, like `panic!(format!("..."))`.
+        index = open_indexof;
+        is_first_argument = true;
+    }
+
+    return StringContext::Unknown;
+}
+
+// INFO: Index of the `(` that is not closed before the end of `before`. Strings after a `,` are
+// not the first argument.
+fn find_open_paren(before: &str, is_first_argument: &mut bool) -> Option<usize> {
+    let mut depth = 0;
+
+    for (index, c) in before.char_indices().rev() {
+        match c {
+            ')' | ']' | '}' => depth += 1,
+            '[' | '{' if depth == 0 => return None,
+            '(' if depth == 0 => return Some(index),
+            '(' | '[' | '{' => depth -= 1,
+            ',' if depth == 0 => *is_first_argument = false,
+            _ => {}
+        }
+    }
+
+    return None;
+}
+
+// INFO: Path at the end of `before`, like `vim.notify`, `log::info!` or `self.logger.warning`.
+fn get_callee(before: &str) -> &str {
+    let before = before.trim_end();
+    let callee_start = before
+        .rfind(|c: char| !c.is_alphanumeric() && !"_.:!".contains(c))
+        .map_or(0, |index| index + 1);
+
+    return &before[callee_start..];
+}
+
+// INFO: Last segment and the receiver before it, `logger` and `info` in `self.logger.info`.
+fn get_segments(callee: &str) -> (&str, &str) {
+    let mut segments = callee
+        .split(['.', ':'])
+        .filter(|segment| !segment.is_empty())
+        .rev();
+
+    let name = segments.next().unwrap_or("");
+    let receiver = segments.next().unwrap_or("");
+
+    return (name, receiver);
+}
+
+fn classify_callee(callee: &str, is_first_argument: bool) -> Option<StringContext> {
+    let (name, receiver) = get_segments(callee);
+
+    if name.is_empty() {
+        return None;
+    }
+
+    if LOG_MACROS.contains(&name) || LOG_RECEIVERS.contains(&receiver.to_lowercase().as_str()) {
+        return Some(StringContext::LogMessage);
+    }
+
+    // INFO: Errors like `ValueError("...")`, `io::Error::new(...)` or `errors.New("...")`.
+    if USER_FACING_CALLS.contains(&name)
+        || callee
+            .split(['.', ':'])
+            .any(|segment| segment.ends_with("Error") || segment.ends_with("Exception"))
+        || (receiver == "errors" && name == "New")
+    {
+        return Some(StringContext::UserFacing);
+    }
+
+    if is_first_argument && INTERNAL_KEY_CALLS.contains(&name) {
+        return Some(StringContext::InternalKey);
+    }
+
+    return None;
+}
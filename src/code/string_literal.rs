@@ -0,0 +1,238 @@
+// INFO: String literals that are read by a machine, not by a person.
+#[derive(Debug, PartialEq)]
+pub enum MachineStringType {
+    Uuid,
+    Semver,
+    MimeType,
+    Url,
+    Json,
+    Sql,
+    Regex,
+    Glob,
+    Path,
+    Hex,
+    Base64,
+    Identifier,
+}
+
+const MIME_TYPES: [&str; 9] = [
+    "application/",
+    "audio/",
+    "font/",
+    "image/",
+    "message/",
+    "model/",
+    "multipart/",
+    "text/",
+    "video/",
+];
+
+const SQL_STATEMENTS: [&str; 9] = [
+    "SELECT ", "INSERT ", "UPDATE ", "DELETE ", "CREATE ", "DROP ", "ALTER ", "WITH ", "PRAGMA ",
+];
+
+const SQL_CLAUSES: [&str; 9] = [
+    " FROM ", " WHERE ", " INTO ", " SET ", " TABLE ", " VALUES", " JOIN ", " AS ", " INDEX ",
+];
+
+// INFO: Type of a string literal that is not prose, like a regex or a UUID. Returns None when the
+// string can be prose and should be checked.
+pub fn classify(value: &str) -> Option<MachineStringType> {
+    let value = value.trim();
+
+    if value.is_empty() {
+        return None;
+    }
+
+    let is_word = !value.contains(char::is_whitespace);
+
+    if is_word && is_uuid(value) {
+        return Some(MachineStringType::Uuid);
+    }
+
+    if is_word && is_semver(value) {
+        return Some(MachineStringType::Semver);
+    }
+
+    if is_mime_type(value) {
+        return Some(MachineStringType::MimeType);
+    }
+
+    if is_word && value.contains("://") {
+        return Some(MachineStringType::Url);
+    }
+
+    if is_json(value) {
+        return Some(MachineStringType::Json);
+    }
+
+    if is_sql(value) {
+        return Some(MachineStringType::Sql);
+    }
+
+    if is_regex(value) {
+        return Some(MachineStringType::Regex);
+    }
+
+    // INFO: A question mark at the end is a question, like `Why?`.
+    if is_word && (value.contains('*') || value.trim_end_matches('?').contains('?')) {
+        return Some(MachineStringType::Glob);
+    }
+
+    if is_word && value.contains(['/', '\\']) {
+        return Some(MachineStringType::Path);
+    }
+
+    if is_word && is_hex(value) {
+        return Some(MachineStringType::Hex);
+    }
+
+    if is_word && is_base64(value) {
+        return Some(MachineStringType::Base64);
+    }
+
+    if is_word && is_identifier(value) {
+        return Some(MachineStringType::Identifier);
+    }
+
+    return None;
+}
+
+// INFO: `123e4567-e89b-12d3-a456-426614174000`
+fn is_uuid(value: &str) -> bool {
+    let parts: Vec<&str> = value.split('-').collect();
+
+    return parts.len() == 5
+        && parts.iter().map(|part| part.len()).eq([8, 4, 4, 4, 12])
+        && parts
+            .iter()
+            .all(|part| part.chars().all(|c| c.is_ascii_hexdigit()));
+}
+
+// INFO: `1.2.3`, `v0.10.0-beta.1` or `^1.2`
+fn is_semver(value: &str) -> bool {
+    let version = value.trim_start_matches(['^', '~', '>', '<', '=', 'v']);
+    let core = match version.find(['-', '+']) {
+        Some(core_end) => &version[..core_end],
+        None => version,
+    };
+    let parts: Vec<&str> = core.split('.').collect();
+
+    return (2..=3).contains(&parts.len())
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()));
+}
+
+// INFO: `application/json` or `text/html; charset=utf-8`
+fn is_mime_type(value: &str) -> bool {
+    let mime_type = value.split(';').next().unwrap_or(value).trim();
+
+    return MIME_TYPES.iter().any(|prefix| {
+        mime_type.strip_prefix(prefix).is_some_and(|subtype| {
+            !subtype.is_empty()
+                && subtype
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || ".+-*".contains(c))
+        })
+    });
+}
+
+// INFO: `{"key": 1}` or `["value"]`
+fn is_json(value: &str) -> bool {
+    return ((value.starts_with('{') && value.ends_with('}'))
+        || (value.starts_with('[') && value.ends_with(']')))
+        && value.contains('"');
+}
+
+// INFO: `SELECT * FROM users WHERE id = ?`, keywords in uppercase so a sentence like `Select the
+// file from the list` is prose.
+fn is_sql(value: &str) -> bool {
+    return SQL_STATEMENTS
+        .iter()
+        .any(|statement| value.starts_with(statement))
+        && SQL_CLAUSES.iter().any(|clause| value.contains(clause));
+}
+
+// INFO: `^[a-z]+$` or `\d+\.\d+`
+fn is_regex(value: &str) -> bool {
+    let is_anchored = value.starts_with('^') || (value.ends_with('$') && !value.ends_with("\\$"));
+    let has_meta_char = value.contains(['[', '\\', '(', '+', '*', '?', '.', '|', '{']);
+
+    if is_anchored && has_meta_char {
+        return true;
+    }
+
+    let constructs = [
+        "\\d", "\\w", "\\s", "\\b", "\\.", "[^", "(?:", "(?P<", ".*", ".+", "]+", "]*", "[a-z",
+        "[A-Z", "[0-9",
+    ];
+
+    return constructs
+        .iter()
+        .filter(|construct| value.contains(*construct))
+        .count()
+        >= 2;
+}
+
+// INFO: `0x1F`, `#ff00aa` or `deadbeef42`
+fn is_hex(value: &str) -> bool {
+    let (hex, has_prefix) = match value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+        .or_else(|| value.strip_prefix('#'))
+    {
+        Some(hex) => (hex, true),
+        None => (value, false),
+    };
+
+    return hex.chars().all(|c| c.is_ascii_hexdigit())
+        && ((has_prefix && 3 <= hex.len())
+            || (6 <= hex.len() && hex.contains(|c: char| c.is_ascii_digit())));
+}
+
+// INFO: `aGVsbG8gd29ybGQ=`, long and mixed case with digits, so long words are not base64.
+fn is_base64(value: &str) -> bool {
+    return 16 <= value.len()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "+/=_-".contains(c))
+        && value.contains(|c: char| c.is_ascii_digit())
+        && value.contains(|c: char| c.is_ascii_uppercase())
+        && value.contains(|c: char| c.is_ascii_lowercase());
+}
+
+// INFO: `user_name`, `USER_NAME`, `user-name`, `user.name` or `userName`, but not a word like
+// `Cancel`.
+fn is_identifier(value: &str) -> bool {
+    if !value
+        .chars()
+        .all(|c| c.is_alphanumeric() || "_-.:".contains(c))
+    {
+        return false;
+    }
+
+    if !value.starts_with(|c: char| c.is_alphanumeric() || c == '_')
+        || !value.ends_with(|c: char| c.is_alphanumeric() || c == '_')
+    {
+        return false;
+    }
+
+    let is_camel_case = value
+        .chars()
+        .zip(value.chars().skip(1))
+        .any(|(c, next)| c.is_lowercase() && next.is_uppercase());
+
+    if value.contains('_') || is_camel_case {
+        return true;
+    }
+
+    // INFO: Hyphens join English words too, like `self-explanatory`, so they only count with
+    // digits, like `utf-8`, or next to other separators, like `app.main-window`.
+    let separator_count = value.matches(['-', '.', ':']).count();
+    if value.contains(|c: char| c.is_ascii_digit()) && separator_count > 0 {
+        return true;
+    }
+
+    return separator_count >= 2 && value.contains(['.', ':']);
+}
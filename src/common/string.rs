@@ -1,4 +1,7 @@
-use std::str::{from_utf8, from_utf8_unchecked};
+use std::{
+    str::{from_utf8, from_utf8_unchecked},
+    sync::Mutex,
+};
 
 use log::{debug, info};
 
@@ -412,3 +415,23 @@ impl<'sp> StrPointer<'sp> for *const str {
         };
     }
 }
+
+static INTERNED_STRS: Mutex<Vec<&'static str>> = Mutex::new(Vec::new());
+
+// INFO: Language definitions are `&'static str`, so the strings of definitions that are loaded at
+// runtime are leaked once and reused. Do not use it for values found while parsing a file.
+pub fn intern_str(value: &str) -> &'static str {
+    let mut interned_strs = match INTERNED_STRS.lock() {
+        Ok(interned_strs) => interned_strs,
+        Err(e) => e.into_inner(),
+    };
+
+    if let Some(interned) = interned_strs.iter().find(|interned| **interned == value) {
+        return interned;
+    }
+
+    let interned: &'static str = Box::leak(value.to_owned().into_boxed_str());
+    interned_strs.push(interned);
+
+    return interned;
+}
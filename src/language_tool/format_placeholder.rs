@@ -0,0 +1,103 @@
+use languagetool_rust::check::Match;
+
+// INFO: Word that replaces a format placeholder, so LanguageTool reads it like any other word.
+pub const PLACEHOLDER_WORD: &str = "X";
+
+// INFO: Value with the format placeholders replaced by a word. Match offsets in the replaced
+// value are moved back to the original value.
+#[derive(Debug)]
+pub struct FormatPlaceholderValue {
+    pub value: String,
+    // INFO: Start of the word in the replaced value and the length of the original placeholder.
+    replacements: Vec<(usize, usize)>,
+}
+
+impl FormatPlaceholderValue {
+    pub fn new(value: &str, placeholders: &[(usize, usize)]) -> Self {
+        let mut replaced = String::with_capacity(value.len());
+        let mut replacements = Vec::with_capacity(placeholders.len());
+        let mut index = 0;
+
+        for (start, end) in placeholders {
+            if *start < index || value.len() < *end {
+                continue;
+            }
+
+            replaced.push_str(&value[index..*start]);
+            replacements.push((replaced.len(), end - start));
+            replaced.push_str(PLACEHOLDER_WORD);
+            index = *end;
+        }
+
+        replaced.push_str(&value[index..]);
+
+        return Self {
+            value: replaced,
+            replacements,
+        };
+    }
+
+    pub fn remap(&self, lang_match: &mut Match) {
+        (lang_match.offset, lang_match.length) =
+            self.original_range(lang_match.offset, lang_match.length);
+    }
+
+    // INFO: LanguageTool offsets are in UTF-16 code units of the checked value, which is the
+    // replaced value with the non prose masked. The mask keeps the byte length, so the byte
+    // offsets of the checked value are the byte offsets of the replaced value.
+    pub fn original_byte_range(
+        &self,
+        checked_value: &str,
+        utf16_offset: usize,
+        utf16_length: usize,
+    ) -> (usize, usize) {
+        let start = byte_offset(checked_value, utf16_offset);
+        let end = byte_offset(checked_value, utf16_offset + utf16_length);
+
+        return self.original_range(start, end - start);
+    }
+
+    // INFO: Offset and length in the original value, a match on the word covers the whole
+    // placeholder.
+    pub fn original_range(&self, offset: usize, length: usize) -> (usize, usize) {
+        let start = self.original_offset(offset, false);
+        let end = self.original_offset(offset + length, true);
+
+        return (start, end - start);
+    }
+
+    fn original_offset(&self, offset: usize, is_end: bool) -> usize {
+        let mut shift: isize = 0;
+
+        for (start, original_len) in &self.replacements {
+            if offset < *start || (offset == *start && is_end) {
+                break;
+            }
+
+            if offset < start + PLACEHOLDER_WORD.len() {
+                return match is_end {
+                    true => (*start as isize + shift) as usize + original_len,
+                    false => (*start as isize + shift) as usize,
+                };
+            }
+
+            shift += *original_len as isize - PLACEHOLDER_WORD.len() as isize;
+        }
+
+        return (offset as isize + shift) as usize;
+    }
+}
+
+fn byte_offset(value: &str, utf16_offset: usize) -> usize {
+    let mut utf16_index = 0;
+
+    for (byte_index, c) in value.char_indices() {
+        if utf16_index >= utf16_offset {
+            return byte_index;
+        }
+
+        utf16_index += c.len_utf16();
+    }
+
+    return value.len();
+}
@@ -5,8 +5,14 @@ use log::{debug, error, info, warn};
 use tokio::{spawn, task::JoinHandle};
 
 use crate::{
-    code::code_file::{Code, CodeBlock, CodeFile, CodeType},
+    code::{
+        annotation_marker,
+        code_file::{BlockType, Code, CodeBlock, CodeFile, CodeType},
+        prose::Prose,
+        string_context, string_literal,
+    },
     lang_tool_client::LangToolClient,
+    language_tool::{format_placeholder::FormatPlaceholderValue, prose_mask},
     nvim_language::line::NvimLangLineType,
 };
 
@@ -14,6 +20,7 @@ use crate::{
 pub enum LanguageToolLineType {
     Block(CodeBlock),
     Code(Code),
+    Prose(Prose),
 }
 
 #[derive(Debug)]
@@ -38,13 +45,18 @@ pub struct LanguageToolLines {
 impl LanguageToolLines {
     async fn generate(code_file: CodeFile, client: Arc<LangToolClient>) -> Vec<LanguageToolLines> {
         // TODO: At this point I also need to create a cash file and handle it.
-        let lang_tool_lines_count = (code_file.blocks.len() + code_file.lines.len()) as usize;
+        let lang_tool_lines_count =
+            (code_file.blocks.len() + code_file.lines.len() + code_file.proses.len()) as usize;
         let mut lang_tool_lines: Vec<LanguageToolLines> = Vec::with_capacity(lang_tool_lines_count);
 
         let code_block_handle: JoinHandle<Vec<LanguageToolLines>> = spawn(
             LanguageToolLines::code_block_lines(code_file.blocks, client.clone()),
         );
 
+        let prose_handle: JoinHandle<Vec<LanguageToolLines>> = spawn(
+            LanguageToolLines::prose_lines(code_file.proses, client.clone()),
+        );
+
         let code_handle: JoinHandle<Vec<LanguageToolLines>> =
             spawn(LanguageToolLines::code_lines(code_file.lines, client));
 
@@ -58,6 +70,16 @@ impl LanguageToolLines {
             ),
         };
 
+        match prose_handle.await {
+            Ok(lines) => {
+                lang_tool_lines.extend(lines);
+            }
+            Err(e) => error!(
+                "LanguageToolLines::generate unable to process prose {:?}",
+                e
+            ),
+        };
+
         match code_handle.await {
             Ok(lines) => {
                 lang_tool_lines.extend(lines);
@@ -78,9 +100,31 @@ impl LanguageToolLines {
 
         let mut lines = Vec::with_capacity(blocks.len());
 
-        for code_block in blocks {
+        for mut code_block in blocks {
+            if matches!(code_block.block_type, BlockType::String) {
+                if let Some(machine_string_type) = string_literal::classify(&code_block.block) {
+                    debug!(
+                        "Skip {:?} string block: {}",
+                        machine_string_type, code_block.block
+                    );
+                    continue;
+                }
+
+                if !string_context::is_checked(&code_block.string_context) {
+                    debug!(
+                        "Skip {:?} string block: {}",
+                        code_block.string_context, code_block.block
+                    );
+                    continue;
+                }
+            }
+
+            let placeholder_value =
+                FormatPlaceholderValue::new(&code_block.block, &code_block.placeholders);
+            code_block.block = prose_mask::mask_non_prose(&placeholder_value.value);
+
             let lang_tool_response = match client.get_lang_tool_v2(&code_block.block).await {
-                Some(res) => res,
+                Some(res) => res.remap_placeholders(&placeholder_value),
                 None => {
                     error!(
                         "LanguageTool Client response is empty. Response Value: {:#?}",
@@ -101,6 +145,40 @@ impl LanguageToolLines {
         return lines;
     }
 
+    async fn prose_lines(
+        proses: Vec<Prose>,
+        client: Arc<LangToolClient>,
+    ) -> Vec<LanguageToolLines> {
+        if proses.is_empty() {
+            return Vec::new();
+        }
+
+        let mut lines = Vec::with_capacity(proses.len());
+
+        for prose in proses {
+            let placeholder_value = FormatPlaceholderValue::new(&prose.value, &prose.placeholders);
+            let checked_value = prose_mask::mask_non_prose(&placeholder_value.value);
+
+            let lang_tool_response = match client.get_lang_tool_v2(&checked_value).await {
+                Some(res) => res.remap_prose_offsets(&checked_value, &placeholder_value),
+                None => {
+                    error!(
+                        "LanguageTool Client response is empty. Response Value: {:#?}",
+                        prose
+                    );
+                    continue;
+                }
+            };
+
+            lines.push(LanguageToolLines {
+                lines: LanguageToolLineType::Prose(prose),
+                lang_tool_response,
+            });
+        }
+
+        return lines;
+    }
+
     async fn code_lines(
         code_lines: Vec<Code>,
         client: Arc<LangToolClient>,
@@ -115,11 +193,44 @@ impl LanguageToolLines {
         for mut code_line in code_lines {
             let client = client.clone();
             handle_check_response.push(spawn(async move {
+                if let CodeType::String = code_line.tp {
+                    if let Some(machine_string_type) = string_literal::classify(&code_line.value) {
+                        debug!("Skip {:?} string: {}", machine_string_type, code_line.value);
+                        return None;
+                    }
+
+                    if !string_context::is_checked(&code_line.string_context) {
+                        debug!(
+                            "Skip {:?} string: {}",
+                            code_line.string_context, code_line.value
+                        );
+                        return None;
+                    }
+                }
+
                 if let CodeType::Code = code_line.tp {
                     // TODO: The word 'Ignore', get the len and minus it form the match.offset
                     code_line.value = format!("Ignore {}", code_line.value);
                 }
 
+                // INFO: Markers like `TODO:` are not prose, the match offsets start after them.
+                if let CodeType::Comment = code_line.tp {
+                    let marker_end = annotation_marker::get_marker_end(&code_line.value);
+                    code_line.value = code_line.value[marker_end..].to_owned();
+                    code_line.column += marker_end;
+
+                    if code_line.value.is_empty() {
+                        return None;
+                    }
+                }
+
+                let placeholder_value =
+                    FormatPlaceholderValue::new(&code_line.value, &code_line.placeholders);
+
+                if !matches!(code_line.tp, CodeType::Code) {
+                    code_line.value = prose_mask::mask_non_prose(&placeholder_value.value);
+                }
+
                 let mut lang_tool_response = match client.get_lang_tool_v2(&code_line.value).await {
                     Some(res) => res,
                     None => {
@@ -145,7 +256,9 @@ impl LanguageToolLines {
                     }
                 };
 
-                lang_tool_response = lang_tool_response.add_to_offset(&code_line);
+                lang_tool_response = lang_tool_response
+                    .add_to_offset(&code_line)
+                    .remap_placeholders(&placeholder_value);
 
                 return Some(LanguageToolLines {
                     lines: LanguageToolLineType::Code(code_line),
@@ -170,6 +283,12 @@ impl LanguageToolLines {
 
 trait CheckResponseTrait: Sized {
     fn add_to_offset(self, code_line: &Code) -> Self;
+    fn remap_placeholders(self, placeholder_value: &FormatPlaceholderValue) -> Self;
+    fn remap_prose_offsets(
+        self,
+        checked_value: &str,
+        placeholder_value: &FormatPlaceholderValue,
+    ) -> Self;
     async fn handle_repetition(
         self,
         code_line: &mut Code,
@@ -234,4 +353,32 @@ impl CheckResponseTrait for CheckResponse {
 
         return self;
     }
+
+    // INFO: Format placeholders are replaced by a word before the check, so match offsets are
+    // moved back to the original value.
+    fn remap_placeholders(mut self, placeholder_value: &FormatPlaceholderValue) -> Self {
+        for lang_match in &mut self.matches {
+            placeholder_value.remap(lang_match);
+        }
+
+        return self;
+    }
+
+    // INFO: Prose offsets are kept as byte offsets in the original prose value, so the match can
+    // be mapped to a line and column with the spans of the prose.
+    fn remap_prose_offsets(
+        mut self,
+        checked_value: &str,
+        placeholder_value: &FormatPlaceholderValue,
+    ) -> Self {
+        for lang_match in &mut self.matches {
+            (lang_match.offset, lang_match.length) = placeholder_value.original_byte_range(
+                checked_value,
+                lang_match.offset,
+                lang_match.length,
+            );
+        }
+
+        return self;
+    }
 }
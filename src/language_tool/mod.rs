@@ -1,4 +1,6 @@
+pub mod format_placeholder;
 pub mod language_tool_file;
+pub mod prose_mask;
 
 use languagetool_rust::{
     check::{Context, Match},
@@ -0,0 +1,167 @@
+use std::iter::{once, repeat_n};
+
+use crate::code::prose::Prose;
+
+// INFO: Chars around a word that are not part of it, like `(see https://example.com).`
+const WORD_START_PUNCTUATION: [char; 6] = ['(', '[', '{', '<', '"', '\''];
+const WORD_END_PUNCTUATION: [char; 12] =
+    [')', ']', '}', '>', ',', '.', ';', ':', '!', '?', '"', '\''];
+
+// INFO: Replace text that is not prose with a mask of the same length in bytes, so offsets in the
+// masked value are the same as in the original value. Masks inline code like `value`, URLs, email
+// addresses, file paths, snake_case words and paths like `Type::path`.
+pub fn mask_non_prose(value: &str) -> String {
+    let mut ranges = find_inline_code(value);
+    let mut word_start = None;
+
+    for (index, c) in value.char_indices().chain(once((value.len(), ' '))) {
+        if !c.is_whitespace() {
+            word_start = word_start.or(Some(index));
+            continue;
+        }
+
+        if let Some(start) = word_start.take() {
+            if let Some(range) = find_word_mask(value, start, index) {
+                ranges.push(range);
+            }
+        }
+    }
+
+    ranges.sort();
+
+    let mut masked = String::with_capacity(value.len());
+    let mut index = 0;
+
+    for (start, end) in ranges {
+        // INFO: Words inside of inline code are already masked.
+        if start < index {
+            continue;
+        }
+
+        masked.push_str(&value[index..start]);
+        masked.extend(repeat_n(Prose::MASK, end - start));
+        index = end;
+    }
+
+    masked.push_str(&value[index..]);
+
+    return masked;
+}
+
+// INFO: Inline code with the backticks, it does not continue on the next line.
+fn find_inline_code(value: &str) -> Vec<(usize, usize)> {
+    let mut ranges = Vec::new();
+    let mut index = 0;
+
+    while let Some(start) = value[index..].find('`').map(|start| index + start) {
+        let end = match value[start + 1..].find(['`', '\n']) {
+            Some(end) if value[start + 1 + end..].starts_with('`') => start + end + 2,
+            Some(end) => {
+                index = start + 1 + end;
+                continue;
+            }
+            None => break,
+        };
+
+        ranges.push((start, end));
+        index = end;
+    }
+
+    return ranges;
+}
+
+fn find_word_mask(value: &str, start: usize, end: usize) -> Option<(usize, usize)> {
+    let word = &value[start..end];
+    let trimmed = word.trim_start_matches(WORD_START_PUNCTUATION);
+    let start = start + word.len() - trimmed.len();
+    let word = trimmed.trim_end_matches(WORD_END_PUNCTUATION);
+
+    if is_url(word) || is_email(word) || is_path(word) || is_code_path(word) || is_snake_case(word)
+    {
+        return Some((start, start + word.len()));
+    }
+
+    return None;
+}
+
+fn is_url(word: &str) -> bool {
+    if word.starts_with("www.") && word.len() > 4 {
+        return true;
+    }
+
+    return match word.split_once("://") {
+        Some((scheme, rest)) => {
+            !scheme.is_empty()
+                && scheme.chars().all(|c| c.is_ascii_alphabetic())
+                && !rest.is_empty()
+        }
+        None => false,
+    };
+}
+
+fn is_email(word: &str) -> bool {
+    let (local, domain) = match word.split_once('@') {
+        Some(email) => email,
+        None => return false,
+    };
+
+    return !local.is_empty()
+        && local
+            .chars()
+            .all(|c| c.is_alphanumeric() || "._%+-".contains(c))
+        && domain.contains('.')
+        && !domain.starts_with('.')
+        && !domain.ends_with('.')
+        && domain
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '.' || c == '-');
+}
+
+// INFO: Unix paths like `/usr/bin`, `~/.config` or `src/lib.rs`, and Windows paths like `C:\Users`.
+fn is_path(word: &str) -> bool {
+    if !word.chars().any(|c| c.is_alphanumeric()) {
+        return false;
+    }
+
+    if ["/", "~/", "./", "../", "\\\\"]
+        .iter()
+        .any(|prefix| word.starts_with(prefix))
+    {
+        return word.len() > 1;
+    }
+
+    let bytes = word.as_bytes();
+    if bytes.len() > 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' {
+        return bytes[2] == b'\\' || bytes[2] == b'/';
+    }
+
+    // INFO: Relative path with a file extension, but not words like `and/or`.
+    return match word.rsplit_once('/') {
+        Some((directory, file)) => {
+            !directory.is_empty()
+                && !directory.split('/').any(str::is_empty)
+                && file
+                    .rsplit_once('.')
+                    .is_some_and(|(name, extension)| !name.is_empty() && !extension.is_empty())
+        }
+        None => false,
+    };
+}
+
+// INFO: Paths like `Type::path`, `std::io::Result` or `Vec::<u8>::new()`.
+fn is_code_path(word: &str) -> bool {
+    return word.contains("::")
+        && word
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_')
+        && word
+            .chars()
+            .all(|c| c.is_alphanumeric() || "_:<>()!&,".contains(c));
+}
+
+fn is_snake_case(word: &str) -> bool {
+    return word.contains('_')
+        && word.chars().any(|c| c.is_alphanumeric())
+        && word.chars().all(|c| c.is_alphanumeric() || c == '_');
+}
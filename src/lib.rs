@@ -2,9 +2,10 @@ use std::sync::{Arc, Mutex};
 
 use common::logger::Logger;
 use log::{error, info};
-use nvim_oxi::{Dictionary, Function, Object, Result};
+use nvim_oxi::{conversion::FromObject, Dictionary, Function, Object, Result};
 
 use crate::{
+    code::{annotation_marker, string_context},
     nvim_lang_dictionary::{NvimLanguageDictionary, NvimLanguageReadonlyDictionary},
     nvim_language::{core::NvimLanguageCore, file::NvimLanguageFile},
 };
@@ -34,7 +35,11 @@ fn main() -> Result<Dictionary> {
     let nvim_language_dictionary_start_processing = nvim_language_dictionary.clone();
     let nvim_lang_file_cp = nvim_lang_file.clone();
 
-    let start_processing_fn = move |file_path: String| {
+    let start_processing = move |(file_path, filetype, commentstring): (
+        String,
+        Option<String>,
+        Option<String>,
+    )| {
         info!("Start Processing file {file_path}");
 
         log::logger().flush();
@@ -54,8 +59,12 @@ fn main() -> Result<Dictionary> {
                 }
             };
 
-            let nvim_lang_file_p =
-                nvim_lang_core.process_file(file_path.clone(), nvim_language_readonly_dictionary);
+            let nvim_lang_file_p = nvim_lang_core.process_file_with_filetype(
+                file_path.clone(),
+                filetype,
+                commentstring,
+                nvim_language_readonly_dictionary,
+            );
 
             let mut nvim_lang_file_gard = match nvim_lang_file.lock() {
                 Ok(l) => l,
@@ -80,6 +89,20 @@ fn main() -> Result<Dictionary> {
         return Result::Ok(());
     };
 
+    let start_processing_with_options = start_processing.clone();
+
+    let start_processing_fn = move |file_path: String| {
+        return start_processing((file_path, None, None));
+    };
+
+    // INFO: Options of the buffer, like `{ filetype = "sh", commentstring = "# %s" }`.
+    let start_processing_with_options_fn = move |(file_path, options): (String, Dictionary)| {
+        let filetype = get_string_option(&options, "filetype");
+        let commentstring = get_string_option(&options, "commentstring");
+
+        return start_processing_with_options((file_path, filetype, commentstring));
+    };
+
     let check_process_fn = move |()| {
         log::logger().flush();
 
@@ -176,34 +199,77 @@ fn main() -> Result<Dictionary> {
         return Result::Ok(nvim_language_dictionary_gard.get_words());
     };
 
+    // INFO: Markers like `TODO:` that are removed from comments before they are checked.
+    let set_annotation_markers_fn = move |markers: Vec<String>| {
+        annotation_marker::set_annotation_markers(&markers);
+
+        return Result::Ok(());
+    };
+
+    // INFO: Contexts of strings that are checked, like `user_facing` or `log_message`.
+    let set_checked_string_contexts_fn = move |contexts: Vec<String>| {
+        string_context::set_checked_string_contexts(&contexts);
+
+        return Result::Ok(());
+    };
+
     let nvim_lang_core_does_support_language = nvim_lang_core.clone();
 
-    let does_support_language_fn = move |file_path: String| {
+    let does_support_language = move |(file_path, filetype, commentstring): (
+        String,
+        Option<String>,
+        Option<String>,
+    )| {
         if file_path.is_empty() {
             return Result::Ok(false);
         }
 
-        if NvimLanguageCore::support_file(&file_path) {
+        if NvimLanguageCore::support_file(
+            &file_path,
+            filetype.as_deref(),
+            commentstring.as_deref(),
+        ) {
             return Result::Ok(true);
         }
 
         return Result::Ok(false);
     };
 
+    let does_support_language_fn = move |file_path: String| {
+        return does_support_language((file_path, None, None));
+    };
+
+    let does_support_language_with_options_fn =
+        move |(file_path, options): (String, Dictionary)| {
+            let filetype = get_string_option(&options, "filetype");
+            let commentstring = get_string_option(&options, "commentstring");
+
+            return does_support_language((file_path, filetype, commentstring));
+        };
+
     info!("Nvim Language Core has Started");
 
     log::logger().flush();
 
     let start_processing_fn = Function::from_fn(start_processing_fn);
+    let start_processing_with_options_fn = Function::from_fn(start_processing_with_options_fn);
     let check_process_fn = Function::from_fn(check_process_fn);
     let languagetool_docker_setup_fn = Function::from_fn(languagetool_docker_setup_fn);
     let add_word_fn = Function::from_fn(add_word_fn);
     let remove_word_fn = Function::from_fn(remove_word_fn);
     let get_words_fn = Function::from_fn(get_words_fn);
     let does_support_language_fn = Function::from_fn(does_support_language_fn);
+    let does_support_language_with_options_fn =
+        Function::from_fn(does_support_language_with_options_fn);
+    let set_annotation_markers_fn = Function::from_fn(set_annotation_markers_fn);
+    let set_checked_string_contexts_fn = Function::from_fn(set_checked_string_contexts_fn);
 
     return Ok(Dictionary::from_iter([
         ("start_processing", Object::from(start_processing_fn)),
+        (
+            "start_processing_with_options",
+            Object::from(start_processing_with_options_fn),
+        ),
         ("check_process", Object::from(check_process_fn)),
         (
             "languagetool_docker_setup",
@@ -216,5 +282,24 @@ fn main() -> Result<Dictionary> {
             "does_support_language",
             Object::from(does_support_language_fn),
         ),
+        (
+            "does_support_language_with_options",
+            Object::from(does_support_language_with_options_fn),
+        ),
+        (
+            "set_annotation_markers",
+            Object::from(set_annotation_markers_fn),
+        ),
+        (
+            "set_checked_string_contexts",
+            Object::from(set_checked_string_contexts_fn),
+        ),
     ]));
 }
+
+// INFO: String value of a Lua options table, missing and non string values are None.
+fn get_string_option(options: &Dictionary, key: &str) -> Option<String> {
+    let option = options.get(key)?;
+
+    return String::from_object(option.clone()).ok();
+}
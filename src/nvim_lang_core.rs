@@ -71,12 +71,9 @@ impl<'lang> NvimLangCore<'lang> {
     }
 
     fn get_file_type(&self, file_path: &String) -> Option<&ProgrammingLanguage> {
-        for lang in &self.programming_languages {
-            if file_path.ends_with(lang.extension) {
-                return Some(lang);
-            }
-        }
-
-        return None;
+        return self
+            .programming_languages
+            .iter()
+            .find(|lang| file_path.ends_with(lang.extension));
     }
 }
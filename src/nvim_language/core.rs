@@ -3,10 +3,7 @@ use std::{future::Future, sync::Arc};
 use log::warn;
 
 use crate::{
-    code::{
-        code_file::CodeFile,
-        programming::{ProgrammingLanguageType, LUA, RUST},
-    },
+    code::{code_file::CodeFile, definition, filetype},
     lang_tool_client::LangToolClient,
     language_tool::language_tool_file::LanguageToolFile,
     nvim_lang_dictionary::NvimLanguageReadonlyDictionary,
@@ -21,6 +18,8 @@ pub struct NvimLanguageCore {
 
 impl NvimLanguageCore {
     pub fn new(lang_tool_url: Option<String>, lang: Option<String>) -> NvimLanguageCore {
+        definition::load_default_language_definitions();
+
         return NvimLanguageCore {
             lang_tool_client: Arc::new(LangToolClient::new(lang_tool_url, lang)),
             file_extension: "",
@@ -48,6 +47,21 @@ impl NvimLanguageCore {
         &self,
         file_path: String,
         nvim_language_readonly_dictionary: NvimLanguageReadonlyDictionary,
+    ) -> NvimLanguageFile {
+        return self.process_file_with_filetype(
+            file_path,
+            None,
+            None,
+            nvim_language_readonly_dictionary,
+        );
+    }
+
+    pub fn process_file_with_filetype(
+        &self,
+        file_path: String,
+        filetype: Option<String>,
+        commentstring: Option<String>,
+        nvim_language_readonly_dictionary: NvimLanguageReadonlyDictionary,
     ) -> NvimLanguageFile {
         if file_path.is_empty() {
             warn!("No file path was provided");
@@ -62,8 +76,13 @@ impl NvimLanguageCore {
 
         return runtime.block_on(async {
             let code_file =
-                match CodeFile::new(file_path.clone(), nvim_language_readonly_dictionary.clone())
-                    .await
+                match CodeFile::new_with_filetype(
+                    file_path.clone(),
+                    filetype,
+                    commentstring,
+                    nvim_language_readonly_dictionary.clone(),
+                )
+                .await
                 {
                     Some(code_file) => code_file,
                     None => {
@@ -86,14 +105,11 @@ impl NvimLanguageCore {
         });
     }
 
-    pub fn support_file(file_path: &String) -> bool {
-        if file_path.ends_with(&RUST.extension) {
-            return true;
-        }
-
-        if file_path.ends_with(&LUA.extension) {
-            return true;
-        }
-        return false;
+    pub fn support_file(
+        file_path: &String,
+        filetype: Option<&str>,
+        commentstring: Option<&str>,
+    ) -> bool {
+        return filetype::detect(file_path, filetype, commentstring).is_some();
     }
 }
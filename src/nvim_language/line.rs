@@ -5,7 +5,10 @@ use log::{debug, info};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    code::code_file::{CodeBlock, CodeLine, CodeType},
+    code::{
+        code_file::{CodeBlock, CodeLine, CodeType},
+        prose::Prose,
+    },
     language_tool::{language_tool_file::LanguageToolLineType, LanguageToolContextTrait},
     nvim_lang_dictionary::NvimLanguageReadonlyDictionary,
 };
@@ -113,9 +116,12 @@ impl NvimLanguageLine {
                     &code.line,
                     chunk,
                     &lang_match,
-                    lang_match.offset,
+                    code.column + lang_match.offset,
                 );
             }
+            LanguageToolLineType::Prose(ref prose) => {
+                return NvimLanguageLine::prose_to_nvim_lang_line(prose, chunk, &lang_match);
+            }
         }
 
         return None;
@@ -127,51 +133,48 @@ impl NvimLanguageLine {
         lang_match: &Match,
     ) -> Option<NvimLanguageLine> {
         let nvim_lang_line_type = NvimLangLineType::get_type(&lang_match.rule.category);
+        let (code_line, start_column) = code_block.position(lang_match.offset)?;
 
-        let mut absolute_len = 0;
-        let mut flage = false;
-
-        for code_line in &code_block.lines {
-            if absolute_len > lang_match.offset {
-                break;
-            }
-
-            let start_column = lang_match.offset - absolute_len;
-
-            // INFO: Increste the absolute length and add one that is the line breack
-            if flage && absolute_len == 0 {
-                absolute_len = code_line.original_line.trim_start().len() + 1;
-            } else if flage {
-                absolute_len = absolute_len + code_line.original_line.len() + 1;
-            }
-
-            // INFO: Subtract starting delimiter from the absolute length.
-            if !flage && absolute_len == 0 {
-                absolute_len = code_line.original_line.trim().len()
-                    - code_block
-                        .code_block_current_line_syntax
-                        .start_delimiter
-                        .len();
-                flage = true;
-            }
-
-            // INFO: Ignore all zero start column typography on code blocks
-            if start_column == 0 && matches!(nvim_lang_line_type, NvimLangLineType::Typography) {
-                return None;
-            }
-
-            match NvimLanguageLine::code_line_to_nvim_lang_line(
-                code_line,
-                chunk,
-                lang_match,
-                start_column,
-            ) {
-                Some(l) => return Some(l),
-                None => continue,
-            }
+        // INFO: Ignore all zero start column typography on code blocks
+        if start_column == 0 && matches!(nvim_lang_line_type, NvimLangLineType::Typography) {
+            return None;
         }
 
-        return None;
+        return NvimLanguageLine::code_line_to_nvim_lang_line(
+            code_line,
+            chunk,
+            lang_match,
+            start_column,
+        );
+    }
+
+    fn prose_to_nvim_lang_line(
+        prose: &Prose,
+        chunk: &str,
+        lang_match: &Match,
+    ) -> Option<NvimLanguageLine> {
+        // INFO: Prose match offsets are already byte offsets in the prose value.
+        let start = lang_match.offset;
+        let end = lang_match.offset + lang_match.length;
+
+        // INFO: Matches that start on a separator or a mask have no place in the file.
+        let (line_number, start_column, end_column) = prose.position(start, end)?;
+
+        return Some(NvimLanguageLine {
+            line_number,
+            start_column,
+            end_column,
+            options: NvimOptions {
+                original: chunk.to_owned(),
+                options: lang_match
+                    .replacements
+                    .iter()
+                    .map(|r| r.value.clone())
+                    .take(20) // TODO: Set 20 as const
+                    .collect(),
+            },
+            data_type: NvimLangLineType::get_type(&lang_match.rule.category),
+        });
     }
 
     fn code_line_to_nvim_lang_line(
@@ -1,12 +1,14 @@
 use log::info;
 use rstest::rstest;
 use std::env;
+use std::path::Path;
 use std::sync::Arc;
 use tokio::runtime::{self, Runtime};
 
 use nvim_lang_core::{
     code::{
-        code_file::{CodeFile, CodeType},
+        code_file::{Code, CodeFile, CodeType},
+        definition,
         programming::{LUA, RUST},
     },
     common::{logger::Logger, test::get_project_path},
@@ -20,14 +22,13 @@ vec![(1,1,"upercase prduct String","pub fn upercase(prduct: String) {}")])]
 #[case(
     "/rust/codes/multiple_code.rs",
 vec![
-        (8,1,"derive Debug","#[derive(Debug)]"),
-        (8,2,"Main Foldr","pub struct MainFoldr {"),
-        (8,3,"foldr path String","    foldr_path: String,"),
-        (8,6,"Main Foldr","impl MainFoldr {"),
-        (8,7,"generte foldr systim String procces Main Foldr","    pub fn generte_foldr(systim: String, procces: u32) -> MainFoldr {"),
-        (8,8,"procces","        if procces == 0 {"),
-        (8,9,"panic","            panic!(\"\");"),
-        (8,12,"Main Foldr foldr path systim","        return MainFoldr { foldr_path: systim };"),
+        (7,2,"Main Foldr","pub struct MainFoldr {"),
+        (7,3,"foldr path String","    foldr_path: String,"),
+        (7,6,"Main Foldr","impl MainFoldr {"),
+        (7,7,"generte foldr systim String procces Main Foldr","    pub fn generte_foldr(systim: String, procces: u32) -> MainFoldr {"),
+        (7,8,"procces","        if procces == 0 {"),
+        (7,9,"panic","            panic!(\"\");"),
+        (7,12,"Main Foldr foldr path systim","        return MainFoldr { foldr_path: systim };"),
     ])]
 fn rust_code_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &str, &str)>) {
     // env::set_var("RUST_BACKTRACE", "1");
@@ -103,3 +104,204 @@ fn lua_code_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &st
 
     // log::logger().flush();
 }
+
+#[rstest]
+#[case(
+    "/rust/codes/literal_code.rs",
+vec![
+        (5,3,"Parser input label", false),
+        (5,4,"Trait Parser quote char", false),
+        (5,5,"TEMPLATE include", false),
+        (5,6,"Parser can't be emty", true),
+        (5,6,"bytes message", false),
+    ])]
+fn rust_literal_code_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &str, bool)>) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        for (index, data) in values.iter().enumerate() {
+            assert_eq!(data.0, code_file.lines.len());
+            let line = &code_file.lines[index];
+            assert_eq!(data.1, line.line.line_number);
+            assert_eq!(data.2, line.value);
+
+            if data.3 {
+                assert_eq!(true, matches!(line.tp, CodeType::String));
+            } else {
+                assert_eq!(true, matches!(line.tp, CodeType::Code));
+            }
+        }
+    });
+}
+
+#[rstest]
+#[case(
+    "/python/codes/simple_code.py",
+vec![(1,1,"upercase prduct prduct name","def upercase_prduct(prduct_name):")])]
+#[case(
+    "/javascript/codes/simple_code.js",
+vec![(1,1,"upercase Name prduct Name","export function upercaseName(prductName) {}")])]
+#[case(
+    "/typescript/codes/simple_code.tsx",
+vec![(1,1,"Prduct Props prduct Name","interface PrductProps { prductName: string } // A simpel comment")])]
+fn lang_code_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &str, &str)>) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        let code_lines: Vec<_> = code_file
+            .lines
+            .iter()
+            .filter(|line| matches!(line.tp, CodeType::Code))
+            .collect();
+
+        for (index, data) in values.iter().enumerate() {
+            assert_eq!(data.0, code_lines.len());
+            let line = code_lines[index];
+            assert_ne!(0, line.hash);
+            assert_eq!(data.1, line.line.line_number);
+            assert_eq!(data.2, line.value);
+            assert_eq!(data.3, line.line.original_line);
+        }
+    });
+}
+
+#[rstest]
+#[case("/c/codes/preprocessor.c")]
+fn c_preprocessor_should_be(#[case] path: &str) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(4, code_file.lines.len());
+        let line = &code_file.lines[0];
+        assert_eq!(2, line.line.line_number);
+        assert_eq!("Lokal header", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Comment));
+        let line = &code_file.lines[1];
+        assert_eq!(3, line.line.line_number);
+        assert_eq!("GRETING Helo world", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+        let line = &code_file.lines[2];
+        assert_eq!(4, line.line.line_number);
+        assert_eq!("and more", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+        let line = &code_file.lines[3];
+        assert_eq!(5, line.line.line_number);
+        assert_eq!("prnt value valeu", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+    });
+}
+
+#[rstest]
+#[case("/definitions", "/definitions/simple_code.nlt")]
+fn language_definition_should_be(#[case] definitions_path: &str, #[case] path: &str) {
+    let runtime = Runtime::new().expect("");
+
+    let definitions_path = get_project_path(definitions_path);
+    let file_path = get_project_path(path);
+
+    // INFO: The invalid definition is skipped.
+    assert_eq!(
+        1,
+        definition::load_language_definitions(Path::new(&definitions_path))
+    );
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(1, code_file.proses.len());
+        assert_eq!("This is a coment", code_file.proses[0].value);
+
+        assert_eq!(2, code_file.lines.len());
+        let line = &code_file.lines[0];
+        assert_eq!(2, line.line.line_number);
+        assert_eq!("Hello wrld", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+        let line = &code_file.lines[1];
+        assert_eq!(2, line.line.line_number);
+        assert_eq!("upercase Name", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+
+        assert_eq!(1, code_file.blocks.len());
+        assert_eq!("Block\ncoment", code_file.blocks[0].block);
+    });
+}
+
+#[rstest]
+#[case(
+    "/rust/codes/inline_blocks.rs",
+    &[
+        (1, 8, "frist note"),
+        (1, 32, "secnd note"),
+        (2, 14, "Frist raw strng"),
+        (2, 37, "Secnd raw strng"),
+        (3, 8, "same nte"),
+        (3, 30, "same nte"),
+    ]
+)]
+#[case(
+    "/lua/codes/inline_blocks.lua",
+    &[
+        (1, 10, "frist note"),
+        (1, 38, "secnd note"),
+        (2, 15, "Frist long strng"),
+        (2, 40, "Secnd long strng"),
+        (3, 10, "same nte"),
+        (3, 34, "same nte"),
+    ]
+)]
+fn inline_block_should_be(#[case] path: &str, #[case] values: &[(usize, usize, &str)]) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        let codes: Vec<&Code> = code_file
+            .lines
+            .iter()
+            .filter(|code| !matches!(code.tp, CodeType::Code))
+            .collect();
+        assert_eq!(values.len(), codes.len());
+
+        for (code, (line_number, column, value)) in codes.iter().zip(values) {
+            assert_eq!(*line_number, code.line.line_number);
+            assert_eq!(*column, code.column);
+            assert_eq!(*value, code.value);
+            assert_eq!(
+                *value,
+                &code.line.original_line[*column..*column + value.len()]
+            );
+        }
+
+        assert_eq!(1, code_file.blocks.len());
+        assert_eq!("thrid note\nthat continues here", code_file.blocks[0].block);
+    });
+}
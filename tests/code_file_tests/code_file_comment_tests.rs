@@ -7,7 +7,9 @@ use tokio::runtime::{self, Runtime};
 
 use nvim_lang_core::{
     code::{
+        annotation_marker,
         code_file::{CodeFile, CodeType},
+        commented_code,
         programming::{LUA, RUST},
     },
     common::{logger::Logger, test::get_project_path},
@@ -15,16 +17,8 @@ use nvim_lang_core::{
 
 #[rstest]
 #[case(
-    "/rust/comments/simple_one_line_comment.rs",
-vec![(1,1,"This is simle one line comment test case.","//This is simle one line comment test case.")])]
-#[case(
-    "/rust/comments/multiple_comments.rs",
-vec![
-        (4,1,"This is multi commmented line.","//This is multi commmented line."),
-        (4,2,"Multiple having or invoving several parts, elements, or members.","//Multiple having or invoving several parts, elements, or members."),
-        (4,3,"","//"),
-        (4,4,"a shop with brances in many places, especialy one selling a specific type of prduct.","//a shop with brances in many places, especialy one selling a specific type of prduct."),
-    ])]
+    "/rust/comments/nested_comment.rs",
+vec![(3,1,"outer    inner comentary    still outer","let value = 1; /* outer /* inner comentary */ still outer */ let other = 2;")])]
 fn rust_comment_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &str, &str)>) {
     // env::set_var("RUST_BACKTRACE", "1");
     // Logger::console_init();
@@ -69,6 +63,9 @@ vec![(1,10, r#"LanguageTool is your intelligent writing assistant for all common
     did you know that you can sea synonyms by double clicking a word? Its a impressively
     versatile tool especially if youd like to tell a colleague from over sea's about what
     happened at 5 PM in the afternoon on Monday, 27 May 2007."#)])]
+#[case(
+    "/rust/comments/nested_comment.rs",
+vec![(1,3, "Start of the block\n      nested examle   \n   the block continus here")])]
 
 fn rust_block_comment_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &str)>) {
     // env::set_var("RUST_BACKTRACE", "1");
@@ -97,19 +94,25 @@ fn rust_block_comment_should_be(#[case] path: &str, #[case] values: Vec<(usize,
 
 #[rstest]
 #[case(
-    "/lua/comments/simple_one_line_comment.lua",
-vec![(1,1,"This is simle one line comment test case.","--This is simle one line comment test case.")])]
-#[case(
-    "/lua/comments/multiple_comments.lua",
-vec![
-        (4,1,"This is multi commmented line.","--This is multi commmented line."),
-        (4,2,"Multiple having or invoving several parts, elements, or members.","--Multiple having or invoving several parts, elements, or members."),
-        (4,3,"","--"),
-        (4,4,"a shop with brances in many places, especialy one selling a specific type of prduct.","--a shop with brances in many places, especialy one selling a specific type of prduct."),
-    ])]
-fn lua_comment_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &str, &str)>) {
-    // env::set_var("RUST_BACKTRACE", "1");
-    // Logger::console_init();
+    "/rust/comments/block_rest_line.rs",
+    vec![
+        (1, "first", 0),
+        (2, "The secnd comment is not dropped", 15),
+        (4, "anothr strng here", 16),
+    ],
+    vec![
+        ("A strng that is\nopen file", vec![1, 2]),
+        ("A blok comment\nend", vec![3, 4]),
+        ("open again\nthis shuld be a comment not code", vec![4, 5]),
+    ],
+    vec![("secnd", 2, 19), ("anothr", 4, 16), ("shuld", 5, 5)]
+)]
+fn block_rest_line_should_be(
+    #[case] path: &str,
+    #[case] line_values: Vec<(usize, &str, usize)>,
+    #[case] block_values: Vec<(&str, Vec<usize>)>,
+    #[case] positions: Vec<(&str, usize, usize)>,
+) {
     let runtime = Runtime::new().expect("");
 
     let file_path = get_project_path(path);
@@ -120,18 +123,41 @@ fn lua_comment_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize,
             .await
             .unwrap();
 
-        for (index, data) in values.iter().enumerate() {
-            assert_eq!(data.0, code_file.lines.len());
-            let line = &code_file.lines[index];
-            assert_ne!(0, line.hash);
-            assert_eq!(data.1, line.line.line_number);
-            assert_eq!(data.2, line.value);
-            assert_eq!(data.3, line.line.original_line);
-            assert_eq!(true, matches!(line.tp, CodeType::Comment));
+        // INFO: Comments, strings and blocks after the end of a block are on the same line.
+        let lines: Vec<(usize, &str, usize)> = code_file
+            .lines
+            .iter()
+            .map(|line| (line.line.line_number, line.value.as_str(), line.column))
+            .collect();
+        assert_eq!(line_values, lines);
+
+        let blocks: Vec<(&str, Vec<usize>)> = code_file
+            .blocks
+            .iter()
+            .map(|block| {
+                let line_numbers = block.lines.iter().map(|line| line.line_number).collect();
+                (block.block.as_str(), line_numbers)
+            })
+            .collect();
+        assert_eq!(block_values, blocks);
+
+        for (word, line_number, column) in positions {
+            let position = code_file
+                .lines
+                .iter()
+                .find_map(|line| {
+                    let start = line.value.find(word)?;
+                    Some((line.line.line_number, line.column + start))
+                })
+                .or_else(|| {
+                    code_file.blocks.iter().find_map(|block| {
+                        let (code_line, column) = block.position(block.block.find(word)?)?;
+                        Some((code_line.line_number, column))
+                    })
+                });
+            assert_eq!(Some((line_number, column)), position);
         }
     });
-
-    // log::logger().flush();
 }
 
 #[rstest]
@@ -177,3 +203,249 @@ fn lua_block_comment_should_be(#[case] path: &str, #[case] values: Vec<(usize, u
 
     // log::logger().flush();
 }
+
+#[rstest]
+#[case(
+    "/rust/comments/simple_one_line_comment.rs",
+    vec!["This is simle one line comment test case."],
+    vec![(0, "simle", Some((1, 10, 15)))]
+)]
+#[case(
+    "/rust/comments/multiple_comments.rs",
+vec![
+        "This is multi commmented line. Multiple having or invoving several parts, elements, or members.",
+        "a shop with brances in many places, especialy one selling a specific type of prduct.",
+    ],
+vec![
+        (0, "commmented", Some((1, 16, 26))),
+        (0, "invoving", Some((2, 21, 29))),
+        (1, "brances", Some((4, 14, 21))),
+    ]
+)]
+#[case(
+    "/rust/comments/paragraph_comment.rs",
+    vec!["The value is red from the configuration file, and the defualt is used when it is mising."],
+vec![
+        (0, "configuration", Some((2, 33, 38))),
+        (0, "guration", Some((3, 7, 15))),
+        (0, "defualt", Some((3, 30, 37))),
+        (0, "mising", Some((4, 23, 29))),
+    ]
+)]
+#[case(
+    "/lua/comments/multiple_comments.lua",
+vec![
+        "This is multi commmented line. Multiple having or invoving several parts, elements, or members.",
+        "a shop with brances in many places, especialy one selling a specific type of prduct.",
+    ],
+vec![
+        (0, "commmented", Some((1, 16, 26))),
+        (1, "prduct", Some((4, 79, 85))),
+    ]
+)]
+#[case(
+    "/python/comments/multiple_comments.py",
+vec![
+        "This is multi commmented line. Multiple having or invoving several parts, elements, or members.",
+        "a shop with brances in many places, especialy one selling a specific type of prduct.",
+    ],
+vec![
+        (0, "invoving", Some((2, 20, 28))),
+        (1, "especialy", Some((4, 37, 46))),
+    ]
+)]
+fn line_comment_should_be(
+    #[case] path: &str,
+    #[case] values: Vec<&str>,
+    #[case] positions: Vec<(usize, &str, Option<(usize, usize, usize)>)>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        // INFO: Consecutive line comments are merged into paragraphs, they are not code lines.
+        let has_comment_line = code_file
+            .lines
+            .iter()
+            .any(|line| matches!(line.tp, CodeType::Comment));
+        assert_eq!(false, has_comment_line);
+        assert_eq!(values.len(), code_file.proses.len());
+
+        for (index, value) in values.iter().enumerate() {
+            assert_eq!(*value, code_file.proses[index].value);
+            assert_ne!(0, code_file.proses[index].hash);
+        }
+
+        for data in positions {
+            let prose = &code_file.proses[data.0];
+            let start = prose.value.find(data.1).unwrap();
+            assert_eq!(data.2, prose.position(start, start + data.1.len()));
+        }
+    });
+}
+
+#[rstest]
+#[case(
+    "/javascript/comments/jsdoc_comment.js",
+vec![(1,5, "Retrun the users full naem.
+                             The frist name.
+                     The ful name.")])]
+fn javascript_block_comment_should_be(
+    #[case] path: &str,
+    #[case] values: Vec<(usize, usize, &str)>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        for (index, data) in values.iter().enumerate() {
+            assert_eq!(data.0, code_file.blocks.len());
+            let block = &code_file.blocks[index];
+            assert_ne!(0, block.hash);
+            assert_eq!(data.1, block.lines.len());
+            assert_eq!(data.2, block.block);
+        }
+    });
+}
+
+#[rstest]
+#[case(
+    "/rust/comments/commented_code.rs",
+    vec!["The valeu is parsed before", "it is used."],
+    vec![(6, "The othr value")],
+    vec!["Start of the block\n                          \n       end of the blok"]
+)]
+#[case(
+    "/lua/comments/commented_code.lua",
+    vec!["Retrun the line count"],
+    vec![],
+    vec![]
+)]
+fn commented_out_code_should_be(
+    #[case] path: &str,
+    #[case] proses: Vec<&str>,
+    #[case] comments: Vec<(usize, &str)>,
+    #[case] blocks: Vec<&str>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        // INFO: Disabled code ends the paragraph and is not checked.
+        let prose_values: Vec<&str> = code_file
+            .proses
+            .iter()
+            .map(|prose| prose.value.as_str())
+            .collect();
+        assert_eq!(proses, prose_values);
+
+        let comment_values: Vec<(usize, &str)> = code_file
+            .lines
+            .iter()
+            .filter(|line| matches!(line.tp, CodeType::Comment))
+            .map(|line| (line.line.line_number, line.value.as_str()))
+            .collect();
+        assert_eq!(comments, comment_values);
+
+        let block_values: Vec<&str> = code_file
+            .blocks
+            .iter()
+            .map(|block| block.block.as_str())
+            .collect();
+        assert_eq!(blocks, block_values);
+    });
+}
+
+#[rstest]
+#[case("debug!(\"CODE: {:#?}\", code);", true)]
+#[case("let value = 1", true)]
+#[case("pub fn value() -> usize", true)]
+#[case("if value == 1 {", true)]
+#[case("return value", false)]
+#[case("if the value is empty (or missing), we skip it", false)]
+#[case("return the default value (see above) when it is unset", false)]
+#[case("for each item in the list (in order) we print the name", false)]
+#[case("Returns \"a\" or \"b\" depending on the \"mode\"", false)]
+fn commented_code_should_be(#[case] comment: &str, #[case] is_code: bool) {
+    assert_eq!(
+        is_code,
+        commented_code::is_code(comment, RUST.reserved_keywords)
+    );
+}
+
+#[rstest]
+#[case("TODO: Fix the value", 6)]
+#[case("  FIXME:   Fix the value", 11)]
+#[case("TODO(name): Fix the value", 12)]
+#[case("TODO(first name): Fix the value", 0)]
+#[case("TODO Fix the value", 0)]
+#[case("TODOS: Fix the value", 0)]
+#[case("The TODO: value", 0)]
+fn annotation_marker_end_should_be(#[case] comment: &str, #[case] marker_end: usize) {
+    assert_eq!(marker_end, annotation_marker::get_marker_end(comment));
+}
+
+#[rstest]
+#[case(
+    "/rust/comments/annotation_marker.rs",
+    vec!["Retrun the valeu from the confg.", "The defualt is used."],
+    vec![(0, "Retrun", Some((2, 20, 26))), (1, "defualt", Some((4, 17, 24)))],
+    vec![("Start of the\n       blok", "Start", 6, 13)]
+)]
+fn annotation_marker_should_be(
+    #[case] path: &str,
+    #[case] values: Vec<&str>,
+    #[case] positions: Vec<(usize, &str, Option<(usize, usize, usize)>)>,
+    #[case] blocks: Vec<(&str, &str, usize, usize)>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        // INFO: A marker starts a new paragraph and is not part of the prose.
+        assert_eq!(values.len(), code_file.proses.len());
+
+        for (index, value) in values.iter().enumerate() {
+            assert_eq!(*value, code_file.proses[index].value);
+        }
+
+        for data in positions {
+            let prose = &code_file.proses[data.0];
+            let start = prose.value.find(data.1).unwrap();
+            assert_eq!(data.2, prose.position(start, start + data.1.len()));
+        }
+
+        assert_eq!(blocks.len(), code_file.blocks.len());
+
+        for (block, data) in code_file.blocks.iter().zip(blocks) {
+            assert_eq!(data.0, block.block);
+            let (code_line, column) = block.position(block.block.find(data.1).unwrap()).unwrap();
+            assert_eq!(data.2, code_line.line_number);
+            assert_eq!(data.3, column);
+        }
+    });
+}
@@ -0,0 +1,174 @@
+use rstest::rstest;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use nvim_lang_core::nvim_lang_dictionary::NvimLanguageDictionary;
+use nvim_lang_core::{
+    code::{
+        code_file::{CodeFile, CodeType},
+        filetype::{find_modeline_filetype, find_shebang_filetype},
+    },
+    common::test::get_project_path,
+};
+
+#[rstest]
+#[case("/filetype/install", None, "Instal the dependancies", "Hello wrld")]
+#[case(
+    "/filetype/template.rs.in",
+    None,
+    "Generated from a templete",
+    "A simple strng"
+)]
+#[case(
+    "/filetype/long_line.rs.in",
+    None,
+    "Generated from a templete",
+    "A simple strng"
+)]
+#[case(
+    "/filetype/plugin.conf",
+    Some("lua"),
+    "This is a comentary",
+    "A simple strng"
+)]
+#[case(
+    "/filetype/plugin.conf",
+    Some("lua.vimrc"),
+    "This is a comentary",
+    "A simple strng"
+)]
+fn filetype_should_be(
+    #[case] path: &str,
+    #[case] filetype: Option<&str>,
+    #[case] comment: &str,
+    #[case] string: &str,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new_with_filetype(
+            file_path,
+            filetype.map(str::to_owned),
+            None,
+            Arc::new(nvim_language_dictionary.to_readonly()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(comment, code_file.proses[0].value);
+        let line = code_file
+            .lines
+            .iter()
+            .find(|line| matches!(line.tp, CodeType::String));
+        assert_eq!(string, line.unwrap().value);
+    });
+}
+
+#[rstest]
+#[case("/filetype/plugin.conf", None)]
+#[case("/filetype/plugin.conf", Some("conf"))]
+fn unknown_filetype_should_be_none(#[case] path: &str, #[case] filetype: Option<&str>) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new_with_filetype(
+            file_path,
+            filetype.map(str::to_owned),
+            None,
+            Arc::new(nvim_language_dictionary.to_readonly()),
+        )
+        .await;
+
+        assert_eq!(true, code_file.is_none());
+    });
+}
+
+#[rstest]
+#[case(
+    "/filetype/default.nix",
+    "nix",
+    "# %s",
+    vec!["the wrld"],
+    vec![],
+    vec!["Instal the dependancies"]
+)]
+#[case(
+    "/filetype/layout.wxml",
+    "wxml",
+    "<!-- %s -->",
+    vec!["This is a comentary"],
+    vec!["Start of a\n  long comentary"],
+    vec![]
+)]
+fn commentstring_should_be(
+    #[case] path: &str,
+    #[case] filetype: &str,
+    #[case] commentstring: &str,
+    #[case] comments: Vec<&str>,
+    #[case] blocks: Vec<&str>,
+    #[case] proses: Vec<&str>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new_with_filetype(
+            file_path,
+            Some(filetype.to_owned()),
+            Some(commentstring.to_owned()),
+            Arc::new(nvim_language_dictionary.to_readonly()),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(comments.len(), code_file.lines.len());
+
+        for (line, comment) in code_file.lines.iter().zip(comments) {
+            assert_eq!(comment, line.value);
+            assert_eq!(true, matches!(line.tp, CodeType::Comment));
+        }
+
+        assert_eq!(blocks.len(), code_file.blocks.len());
+
+        for (block, value) in code_file.blocks.iter().zip(blocks) {
+            assert_eq!(value, block.block);
+        }
+
+        assert_eq!(proses.len(), code_file.proses.len());
+
+        for (prose, value) in code_file.proses.iter().zip(proses) {
+            assert_eq!(value, prose.value);
+        }
+    });
+}
+
+#[rstest]
+#[case("#!/bin/sh", Some("sh"))]
+#[case("#!/bin/bash -e", Some("bash"))]
+#[case("#!/usr/bin/env python3", Some("python"))]
+#[case("#!/usr/bin/env -S deno run", Some("typescript"))]
+#[case("#!/usr/local/bin/lua5.1", Some("lua"))]
+#[case("#!/usr/bin/perl", None)]
+#[case("# not a shebang", None)]
+fn shebang_filetype_should_be(#[case] line: &str, #[case] filetype: Option<&str>) {
+    assert_eq!(filetype, find_shebang_filetype(line));
+}
+
+#[rstest]
+#[case("// vim: set ft=rust:", Some("rust"))]
+#[case("-- vim: filetype=lua", Some("lua"))]
+#[case("# vi: ts=4 ft=sh", Some("sh"))]
+#[case("/* ex: set sw=2 ft=c: */", Some("c"))]
+#[case("navi: ft=rust", None)]
+#[case("// vim: set ts=4:", None)]
+fn modeline_filetype_should_be(#[case] line: &str, #[case] filetype: Option<&str>) {
+    assert_eq!(filetype, find_modeline_filetype(line));
+}
@@ -0,0 +1,244 @@
+use rstest::rstest;
+use std::sync::Arc;
+use tokio::runtime::Runtime;
+
+use nvim_lang_core::nvim_lang_dictionary::NvimLanguageDictionary;
+use nvim_lang_core::{code::code_file::CodeFile, common::test::get_project_path};
+
+#[rstest]
+#[case(
+    "/markdown/prose/readme.md",
+vec![
+        "Instalation",
+        "This is a simple paragraf with 0000000000000 that continues on the next line.",
+        "First item with Ctrl key",
+        "Second item, see 000000000000000000000",
+    ],
+vec![
+        (0, "Instalation", Some((5, 2, 13))),
+        (1, "paragraf", Some((7, 18, 26))),
+        (1, "continues", Some((8, 5, 14))),
+        (1, "0000000000000", None),
+        (2, "key", Some((14, 38, 41))),
+        (3, "Second", Some((15, 6, 12))),
+    ])]
+#[case(
+    "/git/COMMIT_EDITMSG",
+vec![
+        "Fix the paragraf parser",
+        "This line is part of the body.",
+        "The 0000000000 now suports Markdown, see 0000000000000000000000000 for more.",
+    ],
+vec![
+        (0, "paragraf", Some((1, 8, 16))),
+        (2, "suports", Some((4, 19, 26))),
+        (2, "0000000000", None),
+        (2, "for more.", Some((5, 26, 35))),
+    ])]
+#[case(
+    "/config/simple_config.toml",
+vec![
+        "This is a configuraton file for the test case.",
+        "nvim-lang-core",
+        "A langauge checker",
+        "grammer check",
+        "spell",
+        "This is the first lne of the readme.",
+    ],
+vec![
+        (0, "configuraton", Some((1, 12, 24))),
+        (0, "test", Some((2, 10, 14))),
+        (2, "langauge", Some((6, 17, 25))),
+        (5, "readme", Some((12, 7, 13))),
+    ])]
+#[case(
+    "/config/simple_config.yaml",
+vec![
+        "Buld the project",
+        "Continuous integraton",
+        "pull requets",
+        "Instal Rust",
+        "With a trailing coment",
+        "This is a blok scalar value.",
+        "Run the tets",
+    ],
+vec![
+        (1, "integraton", Some((2, 17, 27))),
+        (3, "Instal", Some((9, 15, 21))),
+        (5, "scalar", Some((12, 10, 16))),
+        (6, "tets", Some((15, 46, 50))),
+    ])]
+#[case(
+    "/config/simple_config.json",
+vec![
+        "This is a coment",
+        "A langauge checker",
+        "Block coment on two lines",
+        "grammer",
+        "spell check",
+    ],
+vec![
+        (1, "langauge", Some((3, 13, 21))),
+        (2, "two", Some((7, 8, 11))),
+    ])]
+fn prose_should_be(
+    #[case] path: &str,
+    #[case] values: Vec<&str>,
+    #[case] positions: Vec<(usize, &str, Option<(usize, usize, usize)>)>,
+) {
+    // env::set_var("RUST_BACKTRACE", "1");
+    // Logger::console_init();
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(0, code_file.lines.len());
+        assert_eq!(0, code_file.blocks.len());
+        assert_eq!(values.len(), code_file.proses.len());
+
+        for (index, value) in values.iter().enumerate() {
+            let prose = &code_file.proses[index];
+            assert_ne!(0, prose.hash);
+            assert_eq!(*value, prose.value);
+        }
+
+        for data in positions {
+            let prose = &code_file.proses[data.0];
+            let start = prose.value.find(data.1).unwrap();
+            assert_eq!(data.2, prose.position(start, start + data.1.len()));
+        }
+    });
+
+    // log::logger().flush();
+}
+
+#[rstest]
+#[case(
+    "/rust/doc_comments/doc_comment.rs",
+vec![
+        "Utilites for the 0000000000 module.",
+        "Creates a new parser, see 000000000000000 and 00000.",
+        "This functon uses the default value for 000000000.",
+        "Not a doc comentary.",
+    ],
+vec![
+        (0, "Utilites", Some((1, 4, 12))),
+        (0, "module", Some((1, 32, 38))),
+        (1, "and", Some((3, 46, 49))),
+        (1, "000000000000000", None),
+        (2, "functon", Some((5, 9, 16))),
+        (2, "value", Some((5, 35, 40))),
+        (3, "comentary", Some((10, 15, 24))),
+    ],
+    vec![11]
+)]
+#[case(
+    "/lua/comments/annotation.lua",
+vec![
+        "Retrun the line count of the bufer.",
+        "The bufer numbr",
+        "Extra optons",
+        "The line cuont",
+        "A simple stor",
+        "Every iteam",
+        "Chek the value",
+        "Not a doc comentary",
+    ],
+vec![
+        (0, "Retrun", Some((1, 3, 9))),
+        (1, "numbr", Some((2, 34, 39))),
+        (2, "optons", Some((3, 50, 56))),
+        (3, "cuont", Some((4, 34, 39))),
+        (4, "stor", Some((5, 46, 50))),
+        (5, "iteam", Some((6, 53, 58))),
+        (6, "Chek", Some((7, 34, 38))),
+        (7, "comentary", Some((10, 21, 30))),
+    ],
+    vec![9]
+)]
+#[case(
+    "/rust/codes/attribute_prose.rs",
+vec![
+        "Parses the 0000000 into a tokn list.",
+        "Use the new parsre instead",
+        "The input is emty",
+        "Path to the confg \"file\"",
+    ],
+vec![
+        (0, "tokn", Some((1, 35, 39))),
+        (1, "parsre", Some((2, 50, 56))),
+        (2, "emty", Some((5, 26, 30))),
+        (3, "confg", Some((7, 43, 48))),
+        (3, "file", Some((7, 51, 55))),
+    ],
+    vec![4, 6, 8, 9]
+)]
+#[case(
+    "/rust/doc_comments/bracket_prose.rs",
+vec![
+        "The [optional] limit, see 000000000, 0000000000000 and 000000000000. \
+         Uses the [deprecated] 00000000 flag and 000000 or 00000000.",
+    ],
+vec![
+        (0, "optional", Some((1, 9, 17))),
+        (0, "deprecated", Some((2, 14, 24))),
+    ],
+    vec![3]
+)]
+#[case(
+    "/rust/codes/multi_line_attribute.rs",
+    vec!["Unable to red the file", "Path to the confg file"],
+vec![
+        (0, "red", Some((4, 19, 22))),
+        (1, "confg", Some((9, 28, 33))),
+    ],
+    vec![2, 6, 10]
+)]
+#[case(
+    "/rust/codes/inner_attribute_prose.rs",
+    vec!["Crate levl documentation."],
+    vec![(0, "levl", Some((1, 16, 20)))],
+    vec![4]
+)]
+fn doc_comment_should_be(
+    #[case] path: &str,
+    #[case] values: Vec<&str>,
+    #[case] positions: Vec<(usize, &str, Option<(usize, usize, usize)>)>,
+    #[case] line_numbers: Vec<usize>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        // INFO: Doc comments are only proses, doctests and annotation types are skipped.
+        let code_line_numbers: Vec<usize> = code_file
+            .lines
+            .iter()
+            .map(|line| line.line.line_number)
+            .collect();
+        assert_eq!(line_numbers, code_line_numbers);
+        assert_eq!(values.len(), code_file.proses.len());
+
+        for (index, value) in values.iter().enumerate() {
+            assert_eq!(*value, code_file.proses[index].value);
+        }
+
+        for data in positions {
+            let prose = &code_file.proses[data.0];
+            let start = prose.value.find(data.1).unwrap();
+            assert_eq!(data.2, prose.position(start, start + data.1.len()));
+        }
+    });
+}
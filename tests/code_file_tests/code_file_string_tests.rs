@@ -9,8 +9,11 @@ use nvim_lang_core::{
     code::{
         code_file::{CodeFile, CodeType},
         programming::{ProgrammingLanguage, ProgrammingLanguageType, LUA, RUST},
+        string_context::StringContext,
+        string_literal::{self, MachineStringType},
     },
     common::{logger::Logger, test::get_project_path},
+    language_tool::format_placeholder::FormatPlaceholderValue,
 };
 
 #[rstest]
@@ -107,3 +110,488 @@ fn multiple_string_should_be(#[case] path: &str, #[case] lang_type: ProgrammingL
 
     // log::logger().flush();
 }
+
+#[rstest]
+#[case("/python/strings/multiple_strings.py")]
+fn python_string_should_be(#[case] path: &str) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(8, code_file.lines.len());
+        let line = &code_file.lines[3];
+        assert_eq!(2, line.line.line_number);
+        assert_eq!("purson", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+        let line = &code_file.lines[4];
+        assert_eq!(2, line.line.line_number);
+        assert_eq!("Hello 00000000000", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+        let line = &code_file.lines[5];
+        assert_eq!(2, line.line.line_number);
+        assert_eq!("user naem", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+        let line = &code_file.lines[6];
+        assert_eq!(3, line.line.line_number);
+        assert_eq!("OPARATION_PRDUCT", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+        let line = &code_file.lines[7];
+        assert_eq!(3, line.line.line_number);
+        assert_eq!("value", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+    });
+}
+
+#[rstest]
+#[case(
+    "/python/comments/comment_block.py",
+vec![(1,4, r#"This is multi commmented line.
+Multiple having or invoving several parts, elements, or members.
+
+a shop with brances in many places, especialy one selling a specific type of prduct."#)])]
+fn python_docstring_should_be(#[case] path: &str, #[case] values: Vec<(usize, usize, &str)>) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(1, code_file.lines.len());
+        assert_eq!("main", code_file.lines[0].value);
+
+        for (index, data) in values.iter().enumerate() {
+            assert_eq!(data.0, code_file.blocks.len());
+            let block = &code_file.blocks[index];
+            assert_ne!(0, block.hash);
+            assert_eq!(data.1, block.lines.len());
+            assert_eq!(data.2, block.block);
+        }
+    });
+}
+
+#[rstest]
+#[case(
+    "/python/strings/interpolation_body.py",
+    "Helo 00000000000\nyou have 000000000000000 new mesages",
+    vec![(1, "user name"), (2, "count width")]
+)]
+fn interpolation_body_should_be(
+    #[case] path: &str,
+    #[case] block_value: &str,
+    #[case] interpolations: Vec<(usize, &str)>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(1, code_file.blocks.len());
+        assert_eq!(block_value, code_file.blocks[0].block);
+
+        // INFO: The braces around the string are not interpolations.
+        let codes: Vec<(usize, &str)> = code_file
+            .lines
+            .iter()
+            .filter(|line| matches!(line.tp, CodeType::Code) && line.value != "messages")
+            .map(|line| (line.line.line_number, line.value.as_str()))
+            .collect();
+        assert_eq!(interpolations, codes);
+    });
+}
+
+#[rstest]
+#[case("/javascript/strings/template_literal.js")]
+fn javascript_template_literal_should_be(#[case] path: &str) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(4, code_file.lines.len());
+        let line = &code_file.lines[0];
+        assert_eq!(1, line.line.line_number);
+        assert_eq!("Helo 00000000000, welcome back", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+        let line = &code_file.lines[1];
+        assert_eq!(1, line.line.line_number);
+        assert_eq!("greeting user Naem", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+        let line = &code_file.lines[2];
+        assert_eq!(2, line.line.line_number);
+        assert_eq!("message", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+        let line = &code_file.lines[3];
+        assert_eq!(3, line.line.line_number);
+        assert_eq!("user frst Name count", line.value);
+        assert_eq!(
+            "templete for ${user.frstName} with ${count} mesages.",
+            line.line.original_line
+        );
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+
+        assert_eq!(1, code_file.blocks.len());
+        let block = &code_file.blocks[0];
+        assert_eq!(3, block.lines.len());
+        assert_eq!(
+            "This is a multi line\ntemplete for 0000000000000000 with 00000000 mesages.",
+            block.block
+        );
+    });
+}
+
+#[rstest]
+#[case(
+    "/go/strings/raw_string.go",
+    "Greting",
+    "This is a raw\nstrng that spans lines."
+)]
+#[case("/cpp/strings/raw_string.cpp", "text", "First lne\nsecnd line")]
+fn raw_string_should_be(#[case] path: &str, #[case] code: &str, #[case] block_value: &str) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        let line = code_file
+            .lines
+            .iter()
+            .find(|line| matches!(line.tp, CodeType::Code) && line.value == code);
+        assert_eq!(true, line.is_some());
+
+        if path.ends_with(".cpp") {
+            let line = &code_file.lines[0];
+            assert_eq!("This is a raw strng", line.value);
+            assert_eq!(true, matches!(line.tp, CodeType::String));
+        }
+
+        assert_eq!(1, code_file.blocks.len());
+        let block = &code_file.blocks[0];
+        assert_eq!(2, block.lines.len());
+        assert_eq!(block_value, block.block);
+    });
+}
+
+#[rstest]
+#[case(
+    "/lua/strings/long_bracket.lua",
+    "This is a long strng with ]] inside",
+    &["First lne\nsecnd line with ]=] inside", "A long comentary\nthat ends"]
+)]
+#[case(
+    "/rust/strings/raw_string.rs",
+    "This is a raw strng with \"## inside",
+    &["First lne\nsecnd line with \"#\" inside"]
+)]
+fn leveled_block_should_be(#[case] path: &str, #[case] value: &str, #[case] block_values: &[&str]) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        let line = &code_file.lines[0];
+        assert_eq!(value, line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+
+        assert_eq!(block_values.len(), code_file.blocks.len());
+        for (block, block_value) in code_file.blocks.iter().zip(block_values) {
+            assert_eq!(2, block.lines.len());
+            assert_eq!(*block_value, block.block);
+        }
+    });
+}
+
+#[rstest]
+#[case(
+    "/rust/strings/multi_line_string.rs",
+    &["This is a strng\nthat spans lnes with \\\"quotes\\\"", "First part of the sentnce  \n    continues on the next lne."],
+    &[("strng", 1, 48), ("lnes", 2, 11), ("sentnce", 3, 35), ("continues", 4, 4)]
+)]
+#[case(
+    "/lua/strings/multi_line_string.lua",
+    &["This is a strng   \n    that continus here", "Second strng  \non the next lne", "Not closed   \n    on the next lne"],
+    &[("strng", 1, 52), ("continus", 2, 9), ("Second", 3, 15), ("lne", 4, 12)]
+)]
+fn multi_line_string_should_be(
+    #[case] path: &str,
+    #[case] block_values: &[&str],
+    #[case] positions: &[(&str, usize, usize)],
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        // INFO: Code and strings before the multi line string are kept.
+        let line = &code_file.lines[0];
+        assert_eq!("Greting", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+        let line = &code_file.lines[1];
+        assert_eq!("title message", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::Code));
+
+        let values: Vec<&str> = code_file
+            .blocks
+            .iter()
+            .map(|block| block.block.as_str())
+            .collect();
+        assert_eq!(block_values, values.as_slice());
+
+        for (value, line_number, column) in positions {
+            let (code_line, block_column) = code_file
+                .blocks
+                .iter()
+                .find_map(|block| block.position(block.block.find(value)?))
+                .unwrap();
+            assert_eq!(*line_number, code_line.line_number);
+            assert_eq!(*column, block_column);
+        }
+    });
+}
+
+#[rstest]
+#[case("/shell/strings/heredoc.sh")]
+fn shell_heredoc_should_be(#[case] path: &str) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!("Instal the dependencies", code_file.proses[0].value);
+        let line = &code_file.lines[0];
+        assert_eq!(3, line.line.line_number);
+        assert_eq!("Hello 00000, the proces 0000000 has finished", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+        let line = &code_file.lines[2];
+        assert_eq!(4, line.line.line_number);
+        assert_eq!("Literal $HOME strng", line.value);
+        assert_eq!(true, matches!(line.tp, CodeType::String));
+
+        assert_eq!(2, code_file.blocks.len());
+        let block = &code_file.blocks[0];
+        assert_eq!(4, block.lines.len());
+        assert_eq!("This is a heredoc bloc\nfor 00000 with text.", block.block);
+        let block = &code_file.blocks[1];
+        assert_eq!(3, block.lines.len());
+        assert_eq!("Quoted heredoc is not $expanded here", block.block);
+    });
+}
+
+#[rstest]
+#[case(
+    "/rust/strings/format_string.rs",
+    vec![
+        ("Unable to open file: {}, error: {:?}", vec!["{}", "{:?}"]),
+        ("{{Escaped}} brace and {name:>5} value", vec!["{name:>5}"]),
+        ("A set {with spaces} is not a placeholder", vec![]),
+    ]
+)]
+#[case(
+    "/lua/strings/format_string.lua",
+    vec![
+        ("%5.2f of %-10s is done, 100%% sure", vec!["%5.2f", "%-10s"]),
+        ("Only 50% of it", vec![]),
+    ]
+)]
+#[case(
+    "/python/strings/format_string.py",
+    vec![("%(name)s faild with {0} and {value!r}", vec!["%(name)s", "{0}", "{value!r}"])]
+)]
+fn format_placeholder_should_be(#[case] path: &str, #[case] values: Vec<(&str, Vec<&str>)>) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        let strings: Vec<(&str, Vec<&str>)> = code_file
+            .lines
+            .iter()
+            .filter(|line| matches!(line.tp, CodeType::String))
+            .map(|line| {
+                let placeholders = line
+                    .placeholders
+                    .iter()
+                    .map(|(start, end)| &line.value[*start..*end])
+                    .collect();
+                (line.value.as_str(), placeholders)
+            })
+            .collect();
+        assert_eq!(values, strings);
+    });
+}
+
+#[rstest]
+#[case(
+    "/rust/codes/error_attribute.rs",
+    vec![
+        ("Unable to read {path}: {source}", vec!["{path}", "{source}"], "Unable to read X: X"),
+        ("The {0} file is emty", vec!["{0}"], "The X file is emty"),
+    ]
+)]
+fn prose_format_placeholder_should_be(
+    #[case] path: &str,
+    #[case] values: Vec<(&str, Vec<&str>, &str)>,
+) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        assert_eq!(values.len(), code_file.proses.len());
+
+        for (index, (value, placeholders, replaced)) in values.into_iter().enumerate() {
+            let prose = &code_file.proses[index];
+            assert_eq!(value, prose.value);
+
+            let prose_placeholders: Vec<&str> = prose
+                .placeholders
+                .iter()
+                .map(|(start, end)| &prose.value[*start..*end])
+                .collect();
+            assert_eq!(placeholders, prose_placeholders);
+
+            // INFO: LanguageTool gets the replaced value, so `{path}` is not a spelling error.
+            let placeholder_value = FormatPlaceholderValue::new(&prose.value, &prose.placeholders);
+            assert_eq!(replaced, placeholder_value.value);
+        }
+    });
+}
+
+#[rstest]
+#[case("123e4567-e89b-12d3-a456-426614174000", Some(MachineStringType::Uuid))]
+#[case("v0.10.0-beta.1", Some(MachineStringType::Semver))]
+#[case("application/json", Some(MachineStringType::MimeType))]
+#[case("text/html; charset=utf-8", Some(MachineStringType::MimeType))]
+#[case("https://example.com/docs", Some(MachineStringType::Url))]
+#[case("{\"key\": 1}", Some(MachineStringType::Json))]
+#[case("SELECT * FROM users WHERE id = ?", Some(MachineStringType::Sql))]
+#[case("^[a-z]+$", Some(MachineStringType::Regex))]
+#[case("\\d+\\.\\d+", Some(MachineStringType::Regex))]
+#[case("src/**/*.rs", Some(MachineStringType::Glob))]
+#[case("~/.config/nvim", Some(MachineStringType::Path))]
+#[case("#ff00aa", Some(MachineStringType::Hex))]
+#[case("aGVsbG8gd29ybGQ9PQ==", Some(MachineStringType::Base64))]
+#[case("OPARATION_PRDUCT", Some(MachineStringType::Identifier))]
+#[case("userName", Some(MachineStringType::Identifier))]
+#[case("app.main-window", Some(MachineStringType::Identifier))]
+#[case("prduct", None)]
+#[case("self-explanatory", None)]
+#[case("recieve-only", None)]
+#[case("state-of-the-art", None)]
+#[case("Why?", None)]
+#[case("Select the file from the list", None)]
+#[case("Unable to open file: {}, error: {:?}", None)]
+fn machine_string_should_be(
+    #[case] value: &str,
+    #[case] machine_string_type: Option<MachineStringType>,
+) {
+    assert_eq!(machine_string_type, string_literal::classify(value));
+}
+
+#[rstest]
+#[case(
+    "/rust/strings/string_context.rs",
+    vec![
+        ("Hello wrld, {}", StringContext::UserFacing),
+        ("Loaded the setings", StringContext::LogMessage),
+        ("app", StringContext::LogMessage),
+        ("Skipped a lne", StringContext::LogMessage),
+        ("user name", StringContext::InternalKey),
+        ("Value is mising", StringContext::UserFacing),
+        ("HOME PATH", StringContext::InternalKey),
+        ("Unable to finsh", StringContext::UserFacing),
+        ("Not in a call", StringContext::Unknown),
+        ("quit now", StringContext::InternalKey),
+    ]
+)]
+#[case(
+    "/lua/strings/string_context.lua",
+    vec![
+        ("telescope builtin", StringContext::InternalKey),
+        ("Unable to open fle", StringContext::UserFacing),
+        ("Invalid configration", StringContext::UserFacing),
+        ("Found %d wrds", StringContext::UserFacing),
+        ("user name", StringContext::InternalKey),
+        ("plenary path", StringContext::InternalKey),
+        ("Not in a call", StringContext::Unknown),
+    ]
+)]
+#[case(
+    "/python/strings/string_context.py",
+    vec![
+        ("Faild to connect", StringContext::LogMessage),
+        ("Invalid vlue", StringContext::UserFacing),
+        ("user name", StringContext::InternalKey),
+        ("Default usr", StringContext::Unknown),
+        ("Translated mesage", StringContext::UserFacing),
+    ]
+)]
+fn string_context_should_be(#[case] path: &str, #[case] values: Vec<(&str, StringContext)>) {
+    let runtime = Runtime::new().expect("");
+
+    let file_path = get_project_path(path);
+
+    runtime.block_on(async {
+        let nvim_language_dictionary = NvimLanguageDictionary::new(true);
+        let code_file = CodeFile::new(file_path, Arc::new(nvim_language_dictionary.to_readonly()))
+            .await
+            .unwrap();
+
+        let strings: Vec<(&str, StringContext)> = code_file
+            .lines
+            .iter()
+            .filter(|line| matches!(line.tp, CodeType::String))
+            .map(|line| (line.value.as_str(), line.string_context))
+            .collect();
+        assert_eq!(values, strings);
+    });
+}
@@ -1,5 +1,7 @@
 pub mod code_file_code_tests;
 pub mod code_file_comment_tests;
+pub mod code_file_filetype_tests;
+pub mod code_file_prose_tests;
 pub mod code_file_string_tests;
 
 use log::info;
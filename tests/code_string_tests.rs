@@ -53,16 +53,12 @@ vec![
 Expected::new(2, 8, 14, 2, "prduct", vec!["product"]),
 Expected::new(2, 20, 29, 1, "oparation", vec!["operation"]),
 Expected::new(2, 35, 41, 2, "purson", vec!["person", "parson"]),
-Expected::new(3, 21, 30, 1, "OPARATION", vec!["OPERATION"]),
-Expected::new(3, 31, 37, 1, "PRDUCT", vec!["PRODUCT"])
     ])]
 #[case("/lua/strings/multiple_strings.lua",
 vec![
 Expected::new(2, 8, 14, 2, "prduct", vec!["product"]),
 Expected::new(2, 20, 29, 1, "oparation", vec!["operation"]),
 Expected::new(2, 35, 41, 2, "purson", vec!["person", "parson"]),
-Expected::new(3, 23, 32, 1, "OPARATION", vec!["OPERATION"]),
-Expected::new(3, 33, 39, 1, "PRDUCT", vec!["PRODUCT"])
     ]
 )]
 fn multiple_strings_should_be(#[case] path: &str, #[case] mut expected: Vec<Expected>) {
@@ -80,7 +76,8 @@ fn multiple_strings_should_be(#[case] path: &str, #[case] mut expected: Vec<Expe
     result.expected_sorting_order();
     // debug!("{:#?}", result);
     // log::logger().flush();
-    Expected::data_len_to_be(5, &result);
+    // INFO: `OPARATION_PRDUCT` is an identifier, it is not checked.
+    Expected::data_len_to_be(3, &result);
     for (index, exp) in expected.iter().enumerate() {
         exp.assert(index, &result)
     }
@@ -0,0 +1,5 @@
+#include <stdio.h>
+#include "my_heder.h" // Lokal header
+#define GRETING "Helo world" \
+    " and more"
+int prnt_value(int valeu);
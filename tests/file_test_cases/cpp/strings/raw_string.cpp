@@ -0,0 +1,3 @@
+const char *text = R"tag(This is a raw strng)tag";
+const char *multi = R"(First lne
+secnd line)";
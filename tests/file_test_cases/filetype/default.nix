@@ -0,0 +1,2 @@
+# Instal the dependancies
+{ pkgs ? import <nixpkgs> {} }: pkgs.hello # the wrld
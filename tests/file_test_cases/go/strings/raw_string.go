@@ -0,0 +1,7 @@
+package main
+
+// Greting returns a raw strng.
+func Greting() string {
+	return `This is a raw
+strng that spans lines.`
+}
@@ -0,0 +1,6 @@
+/**
+ * Retrun the users full naem.
+ * @param {string} firstName The frist name.
+ * @returns {string} The ful name.
+ */
+function fullName(firstName) {}
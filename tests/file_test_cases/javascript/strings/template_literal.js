@@ -0,0 +1,4 @@
+const greeting = `Helo ${userNaem}, welcome back`;
+const message = `This is a multi line
+templete for ${user.frstName} with ${count} mesages.
+`;
@@ -0,0 +1,4 @@
+call(--[[ frist note ]] value, --[==[ secnd note ]==] other)
+local text = [[Frist long strng]] .. [=[Secnd long strng]=]
+call(--[[ same nte ]] value, --[[ same nte ]] other) --[[ thrid note
+that continues here ]]
@@ -0,0 +1,10 @@
+---Retrun the line count of the bufer.
+---@param bufnr integer The bufer numbr
+---@param opts? { strict: boolean } | nil # Extra optons
+---@return integer count The line cuont
+---@class (exact) Store: Base, Other A simple stor
+---@field private items table<string, integer> Every iteam
+---@type fun(value: any): boolean Chek the value
+---@alias Mode "a" | "b"
+local function count(bufnr, opts) end
+---------- Not a doc comentary
@@ -0,0 +1,4 @@
+-- Retrun the line count
+-- local count = vim.api.nvim_buf_line_count(0)
+-- print(count)
+local value = 1 -- value = 2
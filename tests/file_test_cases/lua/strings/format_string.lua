@@ -0,0 +1,2 @@
+local text = string.format("%5.2f of %-10s is done, 100%% sure", value, name)
+local other = "Only 50% of it"
@@ -0,0 +1,5 @@
+local text = [=====[This is a long strng with ]] inside]=====]
+local usage = [====[First lne
+secnd line with ]=] inside]====]
+--[======[ A long comentary
+that ends ]======]
@@ -0,0 +1,7 @@
+local title = "Greting"; local message = "This is a strng \z
+    that continus here"
+local other = 'Second strng \
+on the next lne'
+local broken = "Not closed \z
+    on the next lne
+local after = "Normal strng"
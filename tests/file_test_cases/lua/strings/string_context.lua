@@ -0,0 +1,7 @@
+local telescope = require("telescope builtin")
+vim.notify("Unable to open fle", vim.log.levels.ERROR)
+error("Invalid configration")
+print(string.format("Found %d wrds", count))
+local value = options["user name"]
+local keys = require "plenary path"
+local message = "Not in a call"
@@ -0,0 +1,2 @@
+def upercase_prduct(prduct_name):
+    pass
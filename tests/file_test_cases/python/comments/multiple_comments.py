@@ -0,0 +1,4 @@
+#This is multi commmented line.
+#Multiple having or invoving several parts, elements, or members.
+#
+#a shop with brances in many places, especialy one selling a specific type of prduct.
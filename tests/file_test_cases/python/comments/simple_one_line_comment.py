@@ -0,0 +1 @@
+#This is simle one line comment test case.
@@ -0,0 +1 @@
+text = "%(name)s faild with {0} and {value!r}" % values
@@ -0,0 +1,2 @@
+messages = {"greeting": f"""Helo {user_name}
+you have {count:{width}} new mesages""", "other": {1: 2}}
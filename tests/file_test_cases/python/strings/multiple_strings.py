@@ -0,0 +1,3 @@
+def system(system):
+    if "prduct" == "oparation" or 'purson' == f"Hello {user_naem}":
+        value = rb'OPARATION_PRDUCT'
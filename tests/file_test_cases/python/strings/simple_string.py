@@ -0,0 +1,2 @@
+TEXT = \
+    "a shop with brances in many places, especialy one selling a specific type of prduct."
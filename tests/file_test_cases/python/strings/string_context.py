@@ -0,0 +1,4 @@
+logger.warning("Faild to connect")
+raise ValueError("Invalid vlue")
+settings = {"user name": "Default usr"}
+print(_("Translated mesage"))
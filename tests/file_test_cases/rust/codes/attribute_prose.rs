@@ -0,0 +1,10 @@
+#[doc = "Parses the `input` into a tokn list."]
+#[deprecated(since = "1.2.0", note = "Use the new parsre instead")]
+#[derive(Debug, Error)]
+enum ParseError {
+    #[error("The input is emty")]
+    Empty,
+    #[arg(short, long, help = "Path to the confg \"file\"")]
+    Config,
+    #[serde(rename = "configPath")] Path,
+}
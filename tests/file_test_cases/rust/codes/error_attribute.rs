@@ -0,0 +1,7 @@
+#[derive(Debug, Error)]
+enum ReadError {
+    #[error("Unable to read {path}: {source}")]
+    Read { path: String, source: io::Error },
+    #[error("The {0} file is emty")]
+    Empty(String),
+}
@@ -0,0 +1,4 @@
+call(/* frist note */ value, /* secnd note */ other);
+let text = r#"Frist raw strng"# + r#"Secnd raw strng"#;
+call(/* same nte */ value, /* same nte */ other); /* thrid note
+that continues here */
@@ -0,0 +1,4 @@
+#![doc = "Crate levl documentation."]
+#![allow(dead_code)]
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[cfg(feature = "serde_json")]
+#[serde(rename = "userNaem", default)]
+struct Parser<'a> { input: &'a str, label: &'static str }
+impl<'a> Trait for Parser<'a> + 'a { fn quote(&self) -> char { '\'' } }
+const TEMPLATE: &str = include_str!("templetes/mian.txt");
+let bytes = b"raw bytse"; let c = '"'; let message = "Parser can't be emty";
+'outer: loop { break 'outer; }
@@ -0,0 +1,11 @@
+#[derive(Debug, Error)]
+enum ReadError {
+    #[error(
+        "Unable to red the file"
+    )]
+    Read,
+    #[arg(
+        short,
+        help = "Path to the confg file",
+    )] Config,
+}
@@ -0,0 +1,8 @@
+fn main() {
+    // TODO(agent): Retrun the valeu
+    // from the confg.
+    // INFO: The defualt is used.
+    let value = 1; // FIXME: Chek the value
+    /* HACK: Start of the
+       blok */
+}
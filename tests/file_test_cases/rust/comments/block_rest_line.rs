@@ -0,0 +1,5 @@
+let first = "A strng that is
+open file"; // The secnd comment is not dropped
+/* A blok comment
+end */ let b = "anothr strng here"; /* open again
+this shuld be a comment not code */
@@ -0,0 +1,10 @@
+fn main() {
+    // The valeu is parsed before
+    // debug!("CODE: {:#?}", code);
+    // it is used.
+    let value = 1; // let value = 2;
+    let other = 2; // The othr value
+    /* Start of the block
+       output.push(value);
+       end of the blok */
+}
@@ -0,0 +1,5 @@
+let value = 1; /* outer /* inner comentary */ still outer */ let other = 2;
+/* Start of the block
+   /* nested examle */
+   the block continus here */
+fn main() {}
@@ -0,0 +1,6 @@
+fn main() {
+    // The value is red from the confi-
+    // guration file, and the defualt is
+    // used when it is mising.
+    let value = 1;
+}
@@ -0,0 +1,3 @@
+/// The [optional] limit, see [`Limit`], [parse_limit] and [Limit::MAX].
+/// Uses the [deprecated] [Option] flag and [vec!] or [read()].
+pub fn limit() {}
@@ -0,0 +1,11 @@
+//! Utilites for the [`Parser`] module.
+
+/// Creates a new parser, see [`Parser::new`] and [Vec].
+///
+/// This functon uses the [default value](Default) for `options`.
+///
+/// ```
+/// let parser = Parser::new(wrng);
+/// ```
+//// Not a doc comentary.
+pub fn create() {}
@@ -0,0 +1,5 @@
+fn main() {
+    println!("Unable to open file: {}, error: {:?}", path, error);
+    println!("{{Escaped}} brace and {name:>5} value");
+    let text = "A set {with spaces} is not a placeholder";
+}
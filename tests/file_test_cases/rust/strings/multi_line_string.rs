@@ -0,0 +1,4 @@
+let title = "Greting"; let message = "This is a strng
+that spans lnes with \"quotes\"";
+let continued = "First part of the sentnce \
+    continues on the next lne.";
@@ -0,0 +1,3 @@
+let text = r#####"This is a raw strng with "## inside"#####;
+let usage = r#####"First lne
+secnd line with "#" inside"#####;
@@ -0,0 +1,13 @@
+fn main() {
+    println!("Hello wrld, {}", name);
+    info!("Loaded the setings");
+    log::warn!(target: "app", "Skipped a lne");
+    let value = map.get("user name").expect("Value is mising");
+    let path = env::var("HOME PATH");
+    panic!("{}", format!("Unable to finsh"));
+    let message = "Not in a call";
+    match command {
+        "quit now" => {}
+        _ => {}
+    }
+}
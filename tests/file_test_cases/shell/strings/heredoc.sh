@@ -0,0 +1,12 @@
+#!/bin/bash
+# Instal the dependencies
+echo "Hello $USER, the proces ${name} has finished"
+echo 'Literal $HOME strng'
+cat <<EOF > "$file"
+This is a heredoc bloc
+for $USER with text.
+EOF
+cat <<-'END'
+	Quoted heredoc is not $expanded here
+	END
+echo "done"
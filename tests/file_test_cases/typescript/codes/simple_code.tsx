@@ -0,0 +1 @@
+interface PrductProps { prductName: string } // A simpel comment
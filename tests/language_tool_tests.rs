@@ -0,0 +1,98 @@
+use rstest::rstest;
+
+use nvim_lang_core::language_tool::{
+    format_placeholder::FormatPlaceholderValue, prose_mask::mask_non_prose,
+};
+
+#[rstest]
+#[case(
+    "See https://example.com/docs for more.",
+    "See 000000000000000000000000 for more."
+)]
+#[case(
+    "Visit www.example.com, then stop.",
+    "Visit 000000000000000, then stop."
+)]
+#[case(
+    "Send it to (name.surname@example.com).",
+    "Send it to (000000000000000000000000)."
+)]
+#[case(
+    "Read ~/.config/nvim and /usr/bin first",
+    "Read 00000000000000 and 00000000 first"
+)]
+#[case(
+    "Open src/lib.rs or C:\\Users\\name",
+    "Open 0000000000 or 0000000000000"
+)]
+#[case("Call `get_value()` once", "Call 0000000000000 once")]
+#[case("Set max_line_len and MAX_COUNT.", "Set 000000000000 and 000000000.")]
+#[case(
+    "Use std::io::Result or Type::path.",
+    "Use 000000000000000 or 0000000000."
+)]
+#[case(
+    "The first\n`line` and second line",
+    "The first\n000000 and second line"
+)]
+#[case("An unclosed `tick and/or words.", "An unclosed `tick and/or words.")]
+#[case("Use a naïve_value here", "Use a 000000000000 here")]
+fn mask_non_prose_should_be(#[case] value: &str, #[case] masked: &str) {
+    let value_masked = mask_non_prose(value);
+
+    assert_eq!(masked, value_masked);
+    assert_eq!(value.len(), value_masked.len());
+}
+
+#[rstest]
+#[case(
+    "Unable to open file: {}, error: {}",
+    vec![(21, 23), (32, 34)],
+    "Unable to open file: X, error: X",
+    vec![((7, 4), (7, 4)), ((21, 1), (21, 2)), ((24, 5), (25, 5)), ((31, 1), (32, 2))]
+)]
+#[case(
+    "%-10s faild with %5.2f",
+    vec![(0, 5), (17, 22)],
+    "X faild with X",
+    vec![((0, 1), (0, 5)), ((2, 5), (6, 5)), ((0, 7), (0, 11)), ((13, 1), (17, 5))]
+)]
+#[case("No placeholders", vec![], "No placeholders", vec![((3, 12), (3, 12))])]
+fn format_placeholder_value_should_be(
+    #[case] value: &str,
+    #[case] placeholders: Vec<(usize, usize)>,
+    #[case] replaced: &str,
+    #[case] ranges: Vec<((usize, usize), (usize, usize))>,
+) {
+    let placeholder_value = FormatPlaceholderValue::new(value, &placeholders);
+
+    assert_eq!(replaced, placeholder_value.value);
+
+    for (range, original_range) in ranges {
+        assert_eq!(
+            original_range,
+            placeholder_value.original_range(range.0, range.1)
+        );
+    }
+}
+
+#[rstest]
+#[case("Unable to read {path}, see file", vec![(15, 21)], (22, 4), (27, 4), "file")]
+#[case("Naïve {name}, see file", vec![(7, 13)], (13, 4), (19, 4), "file")]
+#[case("Open `naïve()` or {}, then fix", vec![(19, 21)], (27, 3), (28, 3), "fix")]
+fn prose_match_range_should_be(
+    #[case] value: &str,
+    #[case] placeholders: Vec<(usize, usize)>,
+    #[case] utf16_range: (usize, usize),
+    #[case] byte_range: (usize, usize),
+    #[case] word: &str,
+) {
+    let placeholder_value = FormatPlaceholderValue::new(value, &placeholders);
+    let checked_value = mask_non_prose(&placeholder_value.value);
+
+    let (offset, length) =
+        placeholder_value.original_byte_range(&checked_value, utf16_range.0, utf16_range.1);
+
+    assert_eq!(byte_range, (offset, length));
+    assert_eq!(word, &value[offset..offset + length]);
+}